@@ -0,0 +1,103 @@
+//! Compares the generated `Deserialize` against the untagged helper enums it replaced.
+//!
+//! cargo bench -p serde-enum-str --bench de
+
+use core::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use serde_enum_str::Deserialize_enum_str;
+
+#[derive(Deserialize_enum_str, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum Foo {
+    A,
+    #[serde(alias = "bb")]
+    B,
+    C,
+    D,
+    E,
+    #[serde(other)]
+    Other(String),
+}
+
+mod untagged {
+    //! What `Deserialize_enum_str` expanded to before the visitor.
+
+    #[derive(Debug, PartialEq)]
+    pub enum Foo {
+        A,
+        B,
+        C,
+        D,
+        E,
+        Other(String),
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all(deserialize = "snake_case"))]
+    enum __FooDe {
+        A,
+        #[serde(alias = "bb")]
+        B,
+        C,
+        D,
+        E,
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum __FooDeUntagged<__T> {
+        __Enum(__FooDe),
+        __Other(__T),
+    }
+
+    impl<'de> serde::Deserialize<'de> for Foo {
+        fn deserialize<__D>(deserializer: __D) -> Result<Self, __D::Error>
+        where
+            __D: serde::Deserializer<'de>,
+        {
+            let value =
+                match <__FooDeUntagged<String> as serde::Deserialize>::deserialize(deserializer)? {
+                    __FooDeUntagged::__Enum(e) => match e {
+                        __FooDe::A => Foo::A,
+                        __FooDe::B => Foo::B,
+                        __FooDe::C => Foo::C,
+                        __FooDe::D => Foo::D,
+                        __FooDe::E => Foo::E,
+                    },
+                    __FooDeUntagged::__Other(v) => Foo::Other(v),
+                };
+
+            Ok(value)
+        }
+    }
+}
+
+fn bench_de(c: &mut Criterion) {
+    let inputs = [
+        ("first", r#""a""#),
+        ("last", r#""e""#),
+        ("alias", r#""bb""#),
+        ("other", r#""zzz""#),
+    ];
+
+    let mut group = c.benchmark_group("de");
+    for (name, json) in inputs {
+        group.bench_function(format!("visitor/{name}"), |b| {
+            b.iter(|| serde_json::from_str::<Foo>(black_box(json)).unwrap())
+        });
+        group.bench_function(format!("untagged/{name}"), |b| {
+            b.iter(|| serde_json::from_str::<untagged::Foo>(black_box(json)).unwrap())
+        });
+    }
+    group.finish();
+
+    assert_eq!(serde_json::from_str::<Foo>(r#""bb""#).unwrap(), Foo::B);
+    assert_eq!(
+        serde_json::from_str::<untagged::Foo>(r#""bb""#).unwrap(),
+        untagged::Foo::B
+    );
+}
+
+criterion_group!(benches, bench_de);
+criterion_main!(benches);
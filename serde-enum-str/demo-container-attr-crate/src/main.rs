@@ -6,6 +6,7 @@
     PartialEq,
 )]
 #[serde(crate = "serde_enum_str_demo_utils::reexport::serde")]
+#[serde(enum_str_crate = "serde_enum_str_demo_utils::reexport::serde_enum_str")]
 enum Foo {
     A,
 }
@@ -0,0 +1,303 @@
+use alloc::{borrow::ToOwned as _, format, string::String, vec::Vec};
+
+use darling::{FromMeta, ast::NestedMeta};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use syn::{
+    Error as SynError, GenericArgument, GenericParam, Generics, Ident, Lifetime, LifetimeParam,
+    PathArguments, Type, WhereClause, WherePredicate, parse_quote,
+};
+
+/// [Official doc](https://serde.rs/container-attrs.html#bound)
+#[derive(Debug, Clone)]
+pub enum Bound {
+    Normal(Vec<WherePredicate>),
+    Independent {
+        serialize: Option<Vec<WherePredicate>>,
+        deserialize: Option<Vec<WherePredicate>>,
+    },
+}
+impl Bound {
+    pub fn ser_predicates(&self) -> Option<&Vec<WherePredicate>> {
+        match self {
+            Self::Normal(predicates) => Some(predicates),
+            Self::Independent { serialize, .. } => serialize.as_ref(),
+        }
+    }
+
+    pub fn de_predicates(&self) -> Option<&Vec<WherePredicate>> {
+        match self {
+            Self::Normal(predicates) => Some(predicates),
+            Self::Independent { deserialize, .. } => deserialize.as_ref(),
+        }
+    }
+}
+
+#[derive(FromMeta)]
+struct BoundIndependent {
+    #[darling(default)]
+    serialize: Option<String>,
+    #[darling(default)]
+    deserialize: Option<String>,
+}
+
+impl FromMeta for Bound {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        parse_where_predicates(value).map(Self::Normal)
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let independent = BoundIndependent::from_list(items)?;
+        if independent.serialize.is_none() && independent.deserialize.is_none() {
+            return Err(darling::Error::custom(
+                "must be at least one the serialize and deserialize",
+            ));
+        }
+
+        Ok(Self::Independent {
+            serialize: independent
+                .serialize
+                .as_deref()
+                .map(parse_where_predicates)
+                .transpose()?,
+            deserialize: independent
+                .deserialize
+                .as_deref()
+                .map(parse_where_predicates)
+                .transpose()?,
+        })
+    }
+}
+
+fn parse_where_predicates(value: &str) -> darling::Result<Vec<WherePredicate>> {
+    if value.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    syn::parse_str::<WhereClause>(&format!("where {value}"))
+        .map(|where_clause| where_clause.predicates.into_iter().collect())
+        .map_err(|err| darling::Error::custom(format!("invalid bound: {err}")))
+}
+
+//
+pub fn with_where_predicates(generics: &Generics, predicates: &[WherePredicate]) -> Generics {
+    let mut generics = generics.to_owned();
+    generics
+        .make_where_clause()
+        .predicates
+        .extend(predicates.iter().cloned());
+    generics
+}
+
+/// Bound every type parameter used by `types` with `bound`, like serde_derive does for fields.
+pub fn with_bound<'a>(
+    generics: &Generics,
+    types: impl IntoIterator<Item = &'a Type>,
+    bound: &TokenStream,
+) -> Generics {
+    let type_params = generics
+        .type_params()
+        .map(|type_param| &type_param.ident)
+        .collect::<Vec<_>>();
+
+    let mut used = Vec::<&Ident>::new();
+    for r#type in types {
+        collect_idents(quote!(#r#type), &type_params, &mut used);
+    }
+
+    let predicates = used
+        .into_iter()
+        .map(|ident| parse_quote!(#ident: #bound))
+        .collect::<Vec<WherePredicate>>();
+
+    with_where_predicates(generics, &predicates)
+}
+
+fn collect_idents<'a>(tokens: TokenStream, candidates: &[&'a Ident], used: &mut Vec<&'a Ident>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                if let Some(candidate) = candidates.iter().find(|x| **x == &ident)
+                    && !used.contains(candidate)
+                {
+                    used.push(candidate);
+                }
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), candidates, used),
+            _ => {}
+        }
+    }
+}
+
+/// Prepend `'de` to the impl generics of `Deserialize`, outliving every borrowed lifetime.
+pub fn with_lifetime_de(generics: &Generics, borrowed_lifetimes: &[Lifetime]) -> Generics {
+    let mut lifetime_param: LifetimeParam = parse_quote!('de);
+    lifetime_param
+        .bounds
+        .extend(borrowed_lifetimes.iter().cloned());
+
+    let mut generics = generics.to_owned();
+    generics
+        .params
+        .insert(0, GenericParam::Lifetime(lifetime_param));
+    generics
+}
+
+/// The lifetimes in `type`, except `'static` and `'_`.
+pub fn collect_lifetimes(r#type: &Type) -> Vec<Lifetime> {
+    let mut lifetimes = Vec::new();
+    collect_lifetimes_in(quote!(#r#type), &mut lifetimes);
+    lifetimes
+}
+
+fn collect_lifetimes_in(tokens: TokenStream, lifetimes: &mut Vec<Lifetime>) {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(TokenTree::Ident(ident)) = tokens.peek()
+                    && ident != "static"
+                    && ident != "_"
+                {
+                    let lifetime = Lifetime {
+                        apostrophe: punct.span(),
+                        ident: ident.to_owned(),
+                    };
+                    if !lifetimes.contains(&lifetime) {
+                        lifetimes.push(lifetime);
+                    }
+                }
+            }
+            TokenTree::Group(group) => collect_lifetimes_in(group.stream(), lifetimes),
+            _ => {}
+        }
+    }
+}
+
+/// `&str` and `&[u8]` are borrowed without `#[serde(borrow)]`, like serde_derive does.
+pub fn is_implicitly_borrowed(r#type: &Type) -> bool {
+    match r#type {
+        Type::Reference(reference) => {
+            reference.mutability.is_none()
+                && match &*reference.elem {
+                    Type::Path(type_path) => {
+                        type_path.qself.is_none() && type_path.path.is_ident("str")
+                    }
+                    Type::Slice(slice) => {
+                        matches!(&*slice.elem, Type::Path(type_path) if type_path.path.is_ident("u8"))
+                    }
+                    _ => false,
+                }
+        }
+        Type::Group(group) => is_implicitly_borrowed(&group.elem),
+        _ => false,
+    }
+}
+
+/// `Cow<'a, str>`, which serde deserializes as owned unless told otherwise.
+pub fn is_cow_str(r#type: &Type) -> bool {
+    match r#type {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return false;
+            };
+            if segment.ident != "Cow" {
+                return false;
+            }
+            let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+                return false;
+            };
+            let mut arguments = arguments.args.iter();
+            matches!(
+                (arguments.next(), arguments.next(), arguments.next()),
+                (
+                    Some(GenericArgument::Lifetime(_)),
+                    Some(GenericArgument::Type(Type::Path(elem))),
+                    None,
+                ) if elem.path.is_ident("str")
+            )
+        }
+        Type::Group(group) => is_cow_str(&group.elem),
+        _ => false,
+    }
+}
+
+/// `String`, `Box<str>` and `Cow<'a, str>`, which deserialize from any string.
+pub fn is_owned_str(r#type: &Type) -> bool {
+    match r#type {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return false;
+            };
+            match &segment.arguments {
+                PathArguments::None => segment.ident == "String",
+                PathArguments::AngleBracketed(arguments) if segment.ident == "Box" => matches!(
+                    &arguments.args.iter().collect::<Vec<_>>()[..],
+                    [GenericArgument::Type(Type::Path(elem))] if elem.path.is_ident("str")
+                ),
+                _ => is_cow_str(r#type),
+            }
+        }
+        Type::Group(group) => is_owned_str(&group.elem),
+        _ => false,
+    }
+}
+
+/// `is_owned_str` spelled as a full `std` or `alloc` path, `std::string::String` say,
+/// so that a user type of the same name is not taken for it.
+pub fn is_std_owned_str(r#type: &Type) -> bool {
+    match r#type {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let segments = type_path.path.segments.iter().collect::<Vec<_>>();
+            let [krate, module, name] = &segments[..] else {
+                return false;
+            };
+            let module_name = if name.ident == "String" {
+                "string"
+            } else if name.ident == "Box" {
+                "boxed"
+            } else if name.ident == "Cow" {
+                "borrow"
+            } else {
+                return false;
+            };
+            (krate.ident == "std" || krate.ident == "alloc")
+                && module.ident == module_name
+                && krate.arguments.is_none()
+                && module.arguments.is_none()
+                && is_owned_str(r#type)
+        }
+        Type::Group(group) => is_std_owned_str(&group.elem),
+        _ => false,
+    }
+}
+
+/// `is_owned_str` or `&str`, which are `AsRef<str>` and `Into<String>`.
+pub fn is_str_like(r#type: &Type) -> bool {
+    match r#type {
+        Type::Reference(reference) => {
+            reference.mutability.is_none()
+                && matches!(&*reference.elem, Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("str"))
+        }
+        Type::Group(group) => is_str_like(&group.elem),
+        _ => is_owned_str(r#type),
+    }
+}
+
+pub fn is_generic(generics: &Generics) -> bool {
+    !generics.params.is_empty() || generics.where_clause.is_some()
+}
+
+pub fn check_lifetime_de(generics: &Generics) -> Result<(), SynError> {
+    for param in &generics.params {
+        if let GenericParam::Lifetime(lifetime_param) = param
+            && lifetime_param.lifetime.ident == "de"
+        {
+            return Err(SynError::new(
+                lifetime_param.lifetime.ident.span(),
+                "cannot deserialize when there is a lifetime parameter called 'de",
+            ));
+        }
+    }
+    Ok(())
+}
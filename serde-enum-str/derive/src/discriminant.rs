@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use super::input::Input;
+
+/// The discriminant of every variant as a `const __TAG_{index}: #ty`, with Rust's implicit
+/// discriminant rules applied.
+pub struct Tags {
+    pub consts: TokenStream,
+    /// In the same order as `Input::variants`.
+    pub variant_tags: Vec<Ident>,
+    pub default_variant_tag: Option<Ident>,
+}
+
+impl Tags {
+    pub fn new(input: &Input, ty: &Ident) -> Self {
+        let discriminants = input
+            .variants
+            .iter()
+            .map(|variant| variant.discriminant.as_ref())
+            .chain(
+                input
+                    .default_variant
+                    .iter()
+                    .map(|default_variant| default_variant.discriminant.as_ref()),
+            );
+
+        let mut consts = Vec::new();
+        let mut tags = Vec::<Ident>::new();
+        for (index, discriminant) in discriminants.enumerate() {
+            let tag = format_ident!("__TAG_{}", index);
+            let value = match (discriminant, tags.last()) {
+                (Some(discriminant), _) => quote!((#discriminant) as #ty),
+                (None, Some(previous_tag)) => quote!(#previous_tag + 1),
+                (None, None) => quote!(0),
+            };
+            consts.push(quote! {
+                #[allow(dead_code, clippy::all)]
+                const #tag: ::core::primitive::#ty = #value;
+            });
+            tags.push(tag);
+        }
+
+        let default_variant_tag = if input.default_variant.is_some() {
+            tags.pop()
+        } else {
+            None
+        };
+
+        Self {
+            consts: quote!(#(#consts)*),
+            variant_tags: tags,
+            default_variant_tag,
+        }
+    }
+
+    /// `#tag => Some(Foo::A),` for every variant that can be deserialized.
+    pub fn option_arms(&self, input: &Input) -> Vec<TokenStream> {
+        let target = input.target();
+
+        input
+            .variants
+            .iter()
+            .zip(&self.variant_tags)
+            .filter(|(variant, _)| variant.skip_deserializing != Some(true))
+            .map(|(variant, tag)| {
+                let ident = &variant.ident;
+                quote! {
+                    #tag => ::core::option::Option::Some(#target::#ident),
+                }
+            })
+            .collect()
+    }
+}
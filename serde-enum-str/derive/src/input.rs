@@ -0,0 +1,1391 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
+
+use core::fmt;
+
+use darling::{
+    Error as DarlingError, FromDeriveInput, FromMeta, FromVariant, Result as DarlingResult,
+    ast::{Data, Fields},
+    error::Accumulator,
+    util::{Ignored, Override, SpannedValue},
+};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens as _, quote};
+use serde_attributes::{
+    Alias, Rename, RenameAll, RenameIndependent, rename_all::serde_rename_rule::RenameRule,
+};
+use syn::{
+    Attribute, DeriveInput, Error as SynError, Expr, ExprLit, Generics, Ident, Lifetime, Lit, Meta,
+    MetaNameValue, Path, Token, Type, Visibility,
+    parse::{Parse, ParseStream, Parser as _},
+    punctuated::Punctuated,
+};
+
+use super::bound::{
+    Bound, check_lifetime_de, collect_lifetimes, is_implicitly_borrowed, is_std_owned_str,
+    is_str_like,
+};
+
+//
+pub struct Input {
+    pub vis: Visibility,
+    pub ident: Ident,
+    pub generics: Generics,
+    pub rename_all: Option<RenameAll>,
+    /// `#[serde(display_rename_all = "...")]`, for `Display` only.
+    pub display_rename_all: Option<RenameRule>,
+    /// `#[serde(accept_display)]`, `FromStr` also accepts the `Display` names.
+    pub accept_display: bool,
+    pub bound: Option<Bound>,
+    pub compact_repr: Option<Ident>,
+    pub accept_discriminant: Option<AcceptDiscriminant>,
+    /// `#[serde(char)]`, every name is a single character, written with `serialize_char`.
+    pub char: bool,
+    pub remote: Option<Path>,
+    /// `#[serde(on_deprecated = "...")]`, called with the enum name and the deprecated alias.
+    pub on_deprecated: Option<Path>,
+    /// `#[serde(str_interop)]`, `PartialEq<str>`, `AsRef<str>`, `From<Self> for String` and friends.
+    pub str_interop: bool,
+    /// `#[serde(value_enum)]`, a `clap::ValueEnum` impl from the `Deserialize_enum_str` derive.
+    #[cfg(feature = "clap")]
+    pub value_enum: bool,
+    /// `#[serde(json_schema)]`, a `schemars::JsonSchema` impl from the `Serialize_enum_str` derive.
+    #[cfg(feature = "schemars")]
+    pub json_schema: Option<JsonSchema>,
+    /// `#[serde(sqlx)]`, `sqlx::{Type, Encode, Decode}` for a TEXT column.
+    #[cfg(feature = "sqlx")]
+    pub sqlx: bool,
+    /// `#[serde(diesel)]`, diesel's `ToSql<Text, _>` and `FromSql<Text, _>`.
+    #[cfg(feature = "diesel")]
+    pub diesel: bool,
+    /// `#[serde(postgres_types)]`, `postgres_types::{ToSql, FromSql}` for a TEXT column.
+    #[cfg(feature = "postgres-types")]
+    pub postgres_types: bool,
+    pub serde_expr: Expr,
+    pub enum_str_expr: Expr,
+    pub variants: Vec<Variant>,
+    pub pattern_variants: Vec<PatternVariant>,
+    pub default_variant: Option<DefaultVariant>,
+}
+
+pub struct Variant {
+    pub ident: Ident,
+    pub discriminant: Option<Expr>,
+    pub rename: Option<Rename>,
+    pub alias_vec: Option<Vec<Alias>>,
+    /// `#[serde(deprecated_alias = "...")]`, accepted like an alias and recorded.
+    pub deprecated_alias_vec: Vec<String>,
+    pub skip_serializing: Option<bool>,
+    pub skip_deserializing: Option<bool>,
+    pub ascii_case_insensitive: bool,
+    pub display: Option<String>,
+}
+
+/// `#[serde(pattern = "x-{}")] Custom(String)`, or a `#[serde(other)] Code(u16)` before the last
+/// `#[serde(other)]` variant, which is `#[serde(pattern = "{}")]`.
+pub struct PatternVariant {
+    pub ident: Ident,
+    pub prefix: String,
+    pub suffix: String,
+    pub r#type: Type,
+}
+
+/// The options and doc comments for the `JsonSchema` impl.
+#[cfg(feature = "schemars")]
+pub struct JsonSchema {
+    /// `#[serde(json_schema(aliases))]`, also list the deserialize names and aliases.
+    pub aliases: bool,
+    pub description: Option<String>,
+    pub variant_descriptions: Vec<(Ident, String)>,
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema {
+    pub fn variant_description(&self, ident: &Ident) -> Option<&str> {
+        self.variant_descriptions
+            .iter()
+            .find(|(variant_ident, _)| variant_ident == ident)
+            .map(|(_, description)| description.as_str())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AcceptDiscriminant {
+    Integer,
+    /// Also `"2"` for `2`.
+    IntegerOrNumericString,
+}
+
+#[derive(Clone)]
+pub struct DefaultVariant {
+    pub ident: Ident,
+    pub discriminant: Option<Expr>,
+    /// `#[serde(rename = "...")]`, the serialized name of the unit variant.
+    pub rename: Option<String>,
+    pub display: Option<String>,
+    pub r#type: Option<Type>,
+    /// `#[serde(serialize_with = "...")]`, or `serialize` in the `#[serde(with = "...")]` module.
+    pub serialize_with: Option<Path>,
+    /// `#[serde(deserialize_with = "...")]`, or `deserialize` in the `#[serde(with = "...")]` module.
+    pub deserialize_with: Option<Path>,
+    /// Lifetimes that `'de` must outlive, from `#[serde(borrow)]` or an implicitly borrowed type.
+    pub borrowed_lifetimes: Vec<Lifetime>,
+}
+
+impl Input {
+    /// The enum whose values are (de)serialized, `#[serde(remote = "...")]` or this one.
+    pub fn target(&self) -> TokenStream {
+        match &self.remote {
+            Some(remote) => quote!(#remote),
+            None => self.ident.to_token_stream(),
+        }
+    }
+
+    pub fn target_name(&self) -> String {
+        self.remote
+            .as_ref()
+            .and_then(|remote| remote.segments.last())
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_else(|| self.ident.to_string())
+    }
+
+    pub fn default_variant_type(&self) -> Option<&Type> {
+        self.default_variant
+            .as_ref()
+            .and_then(|default_variant| default_variant.r#type.as_ref())
+    }
+
+    /// `FromStr` cannot fail when the `#[serde(other)]` payload is an owned string, named
+    /// by its full path since a bare `String` may be any type in scope.
+    pub fn parses_every_str(&self) -> bool {
+        self.default_variant
+            .as_ref()
+            .is_some_and(|default_variant| {
+                default_variant.deserialize_with.is_none()
+                    && default_variant
+                        .r#type
+                        .as_ref()
+                        .is_some_and(is_std_owned_str)
+            })
+    }
+
+    /// The serialized names of the unit variants, the skipped ones too.
+    pub fn unit_variant_ser_names(&self) -> Vec<(&Ident, String)> {
+        self.variants
+            .iter()
+            .map(|variant| (&variant.ident, self.variant_ser_name(variant)))
+            .chain(
+                self.default_variant
+                    .iter()
+                    .filter(|default_variant| default_variant.r#type.is_none())
+                    .map(|default_variant| {
+                        (
+                            &default_variant.ident,
+                            self.default_variant_ser_name(default_variant),
+                        )
+                    }),
+            )
+            .collect()
+    }
+
+    /// The `#[serde(other)]` payload that is written as is, `Other(String)` without
+    /// `#[serde(serialize_with)]`.
+    pub fn str_payload_variant(&self) -> Option<&DefaultVariant> {
+        self.default_variant.as_ref().filter(|default_variant| {
+            default_variant.serialize_with.is_none()
+                && default_variant.r#type.as_ref().is_some_and(is_str_like)
+        })
+    }
+
+    /// Every variant has a `&str` to hand out, without formatting.
+    pub fn has_static_names(&self) -> bool {
+        self.pattern_variants.is_empty()
+            && (self.default_variant_type().is_none() || self.str_payload_variant().is_some())
+    }
+
+    pub fn has_deprecated_aliases(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| !variant.deprecated_alias_vec.is_empty())
+    }
+
+    pub fn has_explicit_discriminant(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| variant.discriminant.is_some())
+            || self
+                .default_variant
+                .as_ref()
+                .map(|default_variant| default_variant.discriminant.is_some())
+                == Some(true)
+    }
+
+    pub fn borrowed_lifetimes(&self) -> &[Lifetime] {
+        self.default_variant
+            .as_ref()
+            .map(|default_variant| &default_variant.borrowed_lifetimes[..])
+            .unwrap_or_default()
+    }
+
+    pub fn variant_ser_name(&self, variant: &Variant) -> String {
+        if let Some(rename) = &variant.rename
+            && let Some(ser_name) = rename.ser_name()
+        {
+            return ser_name.to_owned();
+        }
+
+        self.ser_name(&variant.ident)
+    }
+
+    /// The `rename_all` applied name.
+    pub fn ser_name(&self, ident: &Ident) -> String {
+        let name = ident.to_string();
+        if let Some(rename_all) = &self.rename_all
+            && let Some(rename_rule) = rename_all.ser_rule()
+        {
+            rename_rule.apply_to_variant(&name)
+        } else {
+            name
+        }
+    }
+
+    /// For the unit `#[serde(other)]` variant.
+    pub fn default_variant_ser_name(&self, default_variant: &DefaultVariant) -> String {
+        match &default_variant.rename {
+            Some(rename) => rename.to_owned(),
+            None => self.ser_name(&default_variant.ident),
+        }
+    }
+
+    /// `#[serde(display)]`, then `display_rename_all`, then the serialized name. A
+    /// `skip_serializing` variant has no serialized name, it keeps the `rename_all` applied one.
+    pub fn variant_display_name(&self, variant: &Variant) -> String {
+        match (&variant.display, &self.display_rename_all) {
+            (Some(display), _) => display.to_owned(),
+            (None, Some(rename_rule)) => rename_rule.apply_to_variant(&variant.ident.to_string()),
+            (None, None) if variant.skip_serializing == Some(true) => self.ser_name(&variant.ident),
+            (None, None) => self.variant_ser_name(variant),
+        }
+    }
+
+    /// For the unit `#[serde(other)]` variant.
+    pub fn default_variant_display_name(&self, default_variant: &DefaultVariant) -> String {
+        match (&default_variant.display, &self.display_rename_all) {
+            (Some(display), _) => display.to_owned(),
+            (None, Some(rename_rule)) => {
+                rename_rule.apply_to_variant(&default_variant.ident.to_string())
+            }
+            (None, None) => self.default_variant_ser_name(default_variant),
+        }
+    }
+
+    pub fn variant_de_name(&self, variant: &Variant) -> String {
+        if let Some(rename) = &variant.rename
+            && let Some(de_name) = rename.de_name()
+        {
+            return de_name.to_owned();
+        }
+
+        let name = variant.ident.to_string();
+        if let Some(rename_all) = &self.rename_all
+            && let Some(rename_rule) = rename_all.de_rule()
+        {
+            rename_rule.apply_to_variant(&name)
+        } else {
+            name
+        }
+    }
+
+    /// Two variants with the same name on the wire would silently shadow one another.
+    fn check_name_collisions(&self, errors: &mut Accumulator) {
+        let mut ser_names: Vec<(String, &Ident)> = vec![];
+        let ser_variants = self
+            .variants
+            .iter()
+            .filter(|variant| variant.skip_serializing != Some(true))
+            .map(|variant| (&variant.ident, self.variant_ser_name(variant)))
+            .chain(
+                self.default_variant
+                    .iter()
+                    .filter(|default_variant| default_variant.r#type.is_none())
+                    .map(|default_variant| {
+                        (
+                            &default_variant.ident,
+                            self.default_variant_ser_name(default_variant),
+                        )
+                    }),
+            );
+        for (ident, name) in ser_variants {
+            if let Some((_, other_ident)) = ser_names.iter().find(|(other, _)| *other == name) {
+                errors.push(spanned_error(
+                    ident.span(),
+                    format!(
+                        "variant {ident} serializes as {name:?}, the same as variant {other_ident}"
+                    ),
+                ));
+            }
+            ser_names.push((name, ident));
+        }
+
+        let mut de_names: Vec<(String, bool, &Ident)> = vec![];
+        for variant in &self.variants {
+            if variant.skip_deserializing == Some(true) {
+                continue;
+            }
+            let ident = &variant.ident;
+            let mut names = self.variant_de_names(variant);
+            let de_names_len = names.len();
+            names.extend(variant.deprecated_alias_vec.iter().cloned());
+            let deprecated_names_len = names.len();
+            if self.accept_display {
+                let display_name = self.variant_display_name(variant);
+                if !names.contains(&display_name) {
+                    names.push(display_name);
+                }
+            }
+            for (i, name) in names.iter().enumerate() {
+                let collides = |other: &str, other_ascii_case_insensitive: bool| {
+                    if variant.ascii_case_insensitive || other_ascii_case_insensitive {
+                        name.eq_ignore_ascii_case(other)
+                    } else {
+                        name == other
+                    }
+                };
+                let kind = match i {
+                    0 => "deserialize name",
+                    i if i < de_names_len => "deserialize alias",
+                    i if i < deprecated_names_len => "deprecated alias",
+                    _ => "display name",
+                };
+                if names[..i].iter().any(|other| collides(other, false)) {
+                    errors.push(spanned_error(
+                        ident.span(),
+                        format!("variant {ident} has the {kind} {name:?} more than once"),
+                    ));
+                } else if let Some((_, _, other_ident)) =
+                    de_names
+                        .iter()
+                        .find(|(other, other_ascii_case_insensitive, _)| {
+                            collides(other, *other_ascii_case_insensitive)
+                        })
+                {
+                    errors.push(spanned_error(
+                        ident.span(),
+                        format!(
+                            "variant {ident} has the {kind} {name:?}, which variant {other_ident} already accepts"
+                        ),
+                    ));
+                }
+            }
+            de_names.extend(
+                names
+                    .into_iter()
+                    .map(|name| (name, variant.ascii_case_insensitive, ident)),
+            );
+        }
+    }
+
+    /// With `#[serde(char)]`, `From<Self> for char` needs every serialized name, even the skipped ones.
+    fn check_char_names(&self, errors: &mut Accumulator) {
+        let is_char = |name: &str| name.chars().count() == 1;
+        for variant in &self.variants {
+            let ident = &variant.ident;
+            let name = self.variant_ser_name(variant);
+            if !is_char(&name) {
+                errors.push(spanned_error(
+                    ident.span(),
+                    format!(
+                        "variant {ident} serializes as {name:?}, which is not a single character"
+                    ),
+                ));
+            }
+            if variant.skip_deserializing == Some(true) {
+                continue;
+            }
+            for de_name in self
+                .variant_de_names(variant)
+                .into_iter()
+                .chain(variant.deprecated_alias_vec.iter().cloned())
+            {
+                // Reported once, as the serialized name.
+                if !is_char(&de_name) && de_name != name {
+                    errors.push(spanned_error(
+                        ident.span(),
+                        format!("variant {ident} deserializes from {de_name:?}, which is not a single character"),
+                    ));
+                }
+            }
+        }
+        if let Some(default_variant) = &self.default_variant
+            && default_variant.r#type.is_none()
+        {
+            let ident = &default_variant.ident;
+            let name = self.default_variant_ser_name(default_variant);
+            if !is_char(&name) {
+                errors.push(spanned_error(
+                    ident.span(),
+                    format!(
+                        "variant {ident} serializes as {name:?}, which is not a single character"
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// The only character of the serialized name, with `#[serde(char)]`.
+    pub fn variant_ser_char(&self, variant: &Variant) -> char {
+        self.variant_ser_name(variant).chars().next().expect("")
+    }
+
+    /// Like `variant_ser_char`, for the unit `#[serde(other)]` variant.
+    pub fn default_variant_ser_char(&self, default_variant: &DefaultVariant) -> char {
+        self.default_variant_ser_name(default_variant)
+            .chars()
+            .next()
+            .expect("")
+    }
+
+    /// The name, then the aliases.
+    pub fn variant_de_names(&self, variant: &Variant) -> Vec<String> {
+        let mut names = vec![self.variant_de_name(variant)];
+        if let Some(alias_vec) = &variant.alias_vec {
+            names.extend(alias_vec.iter().map(|alias| alias.0.to_owned()));
+        }
+        names
+    }
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self, SynError> {
+        let derive_input = DeriveInput::parse(input)?;
+        Self::from_derive_input(&derive_input).map_err(Into::into)
+    }
+}
+
+impl Input {
+    /// Reports every problem at once, each at the offending token.
+    fn from_derive_input(derive_input: &DeriveInput) -> DarlingResult<Self> {
+        let enum_derive_input = EnumDeriveInput::from_derive_input(derive_input)?;
+        let Data::Enum(enum_variants) = &enum_derive_input.data else {
+            return Err(
+                DarlingError::custom("input must be an enum").with_span(&derive_input.ident)
+            );
+        };
+
+        let mut errors = DarlingError::accumulator();
+
+        let vis = enum_derive_input.vis.to_owned();
+        let ident = enum_derive_input.ident.to_owned();
+        let generics = enum_derive_input.generics.to_owned();
+        errors.handle(check_lifetime_de(&generics).map_err(DarlingError::from));
+        let rename_all = enum_derive_input.rename_all.to_owned();
+        let display_rename_all =
+            enum_derive_input
+                .display_rename_all
+                .as_ref()
+                .and_then(|display_rename_all| {
+                    errors.handle(RenameRule::from_rename_all_str(display_rename_all).map_err(
+                        |err| {
+                            spanned_error(
+                                display_rename_all.span(),
+                                err.msg_for_rename_all().replacen(
+                                    "rename_all",
+                                    "display_rename_all",
+                                    1,
+                                ),
+                            )
+                        },
+                    ))
+                });
+        let bound = enum_derive_input.bound.to_owned();
+        let compact_repr = enum_derive_input
+            .compact_repr
+            .as_ref()
+            .and_then(|compact_repr| {
+                if INTEGER_TYPES.contains(&compact_repr.as_str()) {
+                    Some(Ident::new(compact_repr, compact_repr.span()))
+                } else {
+                    errors.push(spanned_error(
+                        compact_repr.span(),
+                        format!(
+                            "#[serde(compact_repr = \"...\")] must be one of {}",
+                            INTEGER_TYPES.join(", ")
+                        ),
+                    ));
+                    None
+                }
+            });
+        let accept_discriminant =
+            enum_derive_input
+                .accept_discriminant
+                .as_ref()
+                .and_then(|accept_discriminant| match &**accept_discriminant {
+                    Override::Inherit => Some(AcceptDiscriminant::Integer),
+                    Override::Explicit(mode) if mode == "numeric_string" => {
+                        Some(AcceptDiscriminant::IntegerOrNumericString)
+                    }
+                    Override::Explicit(_) => {
+                        errors.push(spanned_error(
+                            accept_discriminant.span(),
+                            r#"#[serde(accept_discriminant = "...")] must be "numeric_string""#,
+                        ));
+                        None
+                    }
+                });
+        let remote = enum_derive_input.remote.as_ref().and_then(|remote| {
+            errors.handle(syn::parse_str::<Path>(remote).map_err(|_| {
+                spanned_error(remote.span(), r#"#[serde(remote = "...")] must be a path"#)
+            }))
+        });
+        let on_deprecated = enum_derive_input
+            .on_deprecated
+            .as_ref()
+            .and_then(|on_deprecated| {
+                errors.handle(syn::parse_str::<Path>(on_deprecated).map_err(|_| {
+                    spanned_error(
+                        on_deprecated.span(),
+                        r#"#[serde(on_deprecated = "...")] must be a path"#,
+                    )
+                }))
+            });
+        let str_interop = match &enum_derive_input.str_interop {
+            Some(str_interop) if **str_interop => {
+                if remote.is_some() {
+                    errors.push(spanned_error(
+                        str_interop.span(),
+                        "#[serde(str_interop)] cannot be used with #[serde(remote)]",
+                    ));
+                }
+                true
+            }
+            _ => false,
+        };
+        #[cfg_attr(not(feature = "clap"), allow(unused_variables))]
+        let value_enum = match &enum_derive_input.value_enum {
+            Some(value_enum) if **value_enum => {
+                if cfg!(not(feature = "clap")) {
+                    errors.push(spanned_error(
+                        value_enum.span(),
+                        "#[serde(value_enum)] requires the `clap` feature of serde-enum-str",
+                    ));
+                } else if remote.is_some() {
+                    errors.push(spanned_error(
+                        value_enum.span(),
+                        "#[serde(value_enum)] cannot be used with #[serde(remote)]",
+                    ));
+                }
+                true
+            }
+            _ => false,
+        };
+        #[cfg_attr(not(feature = "schemars"), allow(unused_variables))]
+        let json_schema = enum_derive_input.json_schema.as_ref().map(|json_schema| {
+            if cfg!(not(feature = "schemars")) {
+                errors.push(spanned_error(
+                    json_schema.span(),
+                    "#[serde(json_schema)] requires the `schemars` feature of serde-enum-str",
+                ));
+            } else if remote.is_some() {
+                errors.push(spanned_error(
+                    json_schema.span(),
+                    "#[serde(json_schema)] cannot be used with #[serde(remote)]",
+                ));
+            }
+            match &**json_schema {
+                Override::Inherit => JsonSchemaOptions::default(),
+                Override::Explicit(options) => options.to_owned(),
+            }
+        });
+        // The SQL impls are trait impls, which the orphan rule rules out for a remote enum.
+        let mut sql_attr = |attr: &Option<SpannedValue<bool>>,
+                            name: &str,
+                            feature: &str,
+                            enabled: bool| {
+            let attr = attr.as_ref().filter(|attr| ***attr)?;
+            if !enabled {
+                errors.push(spanned_error(
+                    attr.span(),
+                    format!("#[serde({name})] requires the `{feature}` feature of serde-enum-str"),
+                ));
+            } else if remote.is_some() {
+                errors.push(spanned_error(
+                    attr.span(),
+                    format!("#[serde({name})] cannot be used with #[serde(remote)]"),
+                ));
+            }
+            Some(attr.span())
+        };
+        #[cfg_attr(not(feature = "sqlx"), allow(unused_variables))]
+        let sqlx = sql_attr(
+            &enum_derive_input.sqlx,
+            "sqlx",
+            "sqlx",
+            cfg!(feature = "sqlx"),
+        )
+        .is_some();
+        let diesel_span = sql_attr(
+            &enum_derive_input.diesel,
+            "diesel",
+            "diesel",
+            cfg!(feature = "diesel"),
+        );
+        #[cfg_attr(not(feature = "postgres-types"), allow(unused_variables))]
+        let postgres_types = sql_attr(
+            &enum_derive_input.postgres_types,
+            "postgres_types",
+            "postgres-types",
+            cfg!(feature = "postgres-types"),
+        )
+        .is_some();
+        let serde_expr = enum_derive_input
+            .crate_
+            .as_ref()
+            .and_then(|crate_str| {
+                errors.handle(syn::parse_str::<Expr>(crate_str).map_err(|_| {
+                    spanned_error(
+                        crate_str.span(),
+                        r#"#[serde(crate = "...")] must be an Expr"#,
+                    )
+                }))
+            })
+            .unwrap_or_else(|| syn::parse_str::<Expr>("serde").expect(""));
+        let enum_str_expr = enum_derive_input
+            .enum_str_crate
+            .as_ref()
+            .and_then(|enum_str_crate_str| {
+                errors.handle(syn::parse_str::<Expr>(enum_str_crate_str).map_err(|_| {
+                    spanned_error(
+                        enum_str_crate_str.span(),
+                        r#"#[serde(enum_str_crate = "...")] must be an Expr"#,
+                    )
+                }))
+            })
+            .unwrap_or_else(|| syn::parse_str::<Expr>("::serde_enum_str").expect(""));
+
+        if enum_variants.is_empty() {
+            errors
+                .push(DarlingError::custom("there must be at least one variant").with_span(&ident));
+        }
+
+        // The `#[serde(other)]` variants are the trailing ones, the last is the catch-all.
+        let fallback_start = enum_variants
+            .iter()
+            .rposition(|enum_variant| !enum_variant.is_other)
+            .map_or(0, |i| i + 1);
+        for enum_variant in &enum_variants[..fallback_start] {
+            if enum_variant.is_other {
+                errors.push(
+                    DarlingError::custom("the #[serde(other)] variants should be at the end")
+                        .with_span(&enum_variant.ident),
+                );
+            }
+        }
+
+        let mut variants = vec![];
+        let mut pattern_variants = vec![];
+        let mut default_variant = None;
+        for (i, enum_variant) in enum_variants.iter().enumerate() {
+            if enum_variant.is_other {
+                if i + 1 < enum_variants.len() {
+                    pattern_variants.extend(errors.handle(parse_fallback_variant(enum_variant)));
+                } else {
+                    default_variant = errors.handle(parse_default_variant(enum_variant));
+                }
+            } else if let Some(pattern) = &enum_variant.pattern {
+                pattern_variants
+                    .extend(errors.handle(parse_pattern_variant(enum_variant, pattern)));
+            } else {
+                variants.extend(errors.handle(parse_variant(
+                    enum_variant,
+                    enum_derive_input.ascii_case_insensitive,
+                )));
+            }
+        }
+
+        if !pattern_variants.is_empty() {
+            let span = compact_repr.as_ref().map(Ident::span).or_else(|| {
+                enum_derive_input
+                    .accept_discriminant
+                    .as_ref()
+                    .map(|accept_discriminant| accept_discriminant.span())
+            });
+            if let Some(span) = span {
+                errors.push(spanned_error(
+                    span,
+                    "#[serde(pattern)] variants and multiple #[serde(other)] variants cannot be used with #[serde(compact_repr)] or #[serde(accept_discriminant)]",
+                ));
+            }
+        }
+
+        if let Some(compact_repr) = &compact_repr
+            && let Some(default_variant) = &default_variant
+            && (default_variant.serialize_with.is_some()
+                || default_variant.deserialize_with.is_some())
+        {
+            errors.push(spanned_error(
+                compact_repr.span(),
+                "#[serde(compact_repr)] cannot be used with #[serde(with)], #[serde(serialize_with)] or #[serde(deserialize_with)] on the #[serde(other)] variant",
+            ));
+        }
+
+        let char_span = enum_derive_input
+            .char
+            .as_ref()
+            .filter(|char| ***char)
+            .map(|char| char.span());
+        if let Some(span) = char_span {
+            if !pattern_variants.is_empty() {
+                errors.push(spanned_error(
+                    span,
+                    "#[serde(char)] cannot be used with #[serde(pattern)] variants or multiple #[serde(other)] variants",
+                ));
+            }
+            if compact_repr.is_some() || accept_discriminant.is_some() {
+                errors.push(spanned_error(
+                    span,
+                    "#[serde(char)] cannot be used with #[serde(compact_repr)] or #[serde(accept_discriminant)]",
+                ));
+            }
+        }
+
+        let input = Self {
+            vis,
+            ident,
+            generics,
+            rename_all,
+            display_rename_all,
+            accept_display: enum_derive_input.accept_display.unwrap_or_default(),
+            bound,
+            compact_repr,
+            accept_discriminant,
+            char: char_span.is_some(),
+            remote,
+            on_deprecated,
+            str_interop,
+            #[cfg(feature = "clap")]
+            value_enum,
+            #[cfg(feature = "schemars")]
+            json_schema: json_schema.map(|options| JsonSchema {
+                aliases: options.aliases,
+                description: doc_string(&enum_derive_input.attrs),
+                variant_descriptions: enum_variants
+                    .iter()
+                    .filter_map(|enum_variant| {
+                        doc_string(&enum_variant.attrs)
+                            .map(|description| (enum_variant.ident.to_owned(), description))
+                    })
+                    .collect(),
+            }),
+            #[cfg(feature = "sqlx")]
+            sqlx,
+            #[cfg(feature = "diesel")]
+            diesel: diesel_span.is_some(),
+            #[cfg(feature = "postgres-types")]
+            postgres_types,
+            serde_expr,
+            enum_str_expr,
+            variants,
+            pattern_variants,
+            default_variant,
+        };
+        input.check_name_collisions(&mut errors);
+        if let Some(on_deprecated) = &enum_derive_input.on_deprecated
+            && !input.has_deprecated_aliases()
+        {
+            errors.push(spanned_error(
+                on_deprecated.span(),
+                "#[serde(on_deprecated)] requires a #[serde(deprecated_alias)] on some variant",
+            ));
+        }
+        if let Some(span) = diesel_span
+            && !input.has_static_names()
+        {
+            errors.push(spanned_error(
+                span,
+                "#[serde(diesel)] binds a borrowed &str, it cannot be used with #[serde(pattern)] variants or a #[serde(other)] payload that is not a string",
+            ));
+        }
+        if input.char {
+            input.check_char_names(&mut errors);
+        }
+
+        errors.finish_with(input)
+    }
+}
+
+fn spanned_error(span: Span, message: impl fmt::Display) -> DarlingError {
+    SynError::new(span, message).into()
+}
+
+/// The `///` lines, trimmed and joined like rustdoc's first pass, `None` when there are none.
+#[cfg(feature = "schemars")]
+fn doc_string(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    }),
+                ..
+            }) => Some(lit_str.value()),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.lines()
+                .map(|line| line.trim().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let description = lines.join("\n").trim().to_owned();
+    (!description.is_empty()).then_some(description)
+}
+
+fn parse_variant(
+    enum_variant: &EnumVariant,
+    ascii_case_insensitive: Option<bool>,
+) -> DarlingResult<Variant> {
+    let mut errors = DarlingError::accumulator();
+
+    if let Some(r#type) = enum_variant.fields.iter().next() {
+        errors.push(DarlingError::custom("must be a unit variant").with_span(r#type));
+    }
+    if let Some(borrow) = &enum_variant.borrow {
+        errors.push(spanned_error(
+            borrow.span(),
+            "#[serde(borrow)] is only allowed on the #[serde(other)] variant",
+        ));
+    }
+    if let Some(span) = enum_variant.with_span() {
+        errors.push(spanned_error(span, WITH_ONLY_ON_OTHER));
+    }
+
+    errors.finish_with(Variant {
+        ident: enum_variant.ident.to_owned(),
+        discriminant: enum_variant.discriminant.to_owned(),
+        rename: enum_variant
+            .rename
+            .as_ref()
+            .map(|rename| rename.0.to_owned()),
+        alias_vec: if enum_variant.alias_vec.is_empty() {
+            None
+        } else {
+            Some(enum_variant.alias_vec.to_owned())
+        },
+        deprecated_alias_vec: enum_variant
+            .deprecated_alias_vec
+            .iter()
+            .map(|deprecated_alias| (**deprecated_alias).to_owned())
+            .collect(),
+        skip_serializing: enum_variant.skip_serializing.or(enum_variant.skip),
+        skip_deserializing: enum_variant.skip_deserializing.or(enum_variant.skip),
+        ascii_case_insensitive: enum_variant
+            .ascii_case_insensitive
+            .or(ascii_case_insensitive)
+            .unwrap_or_default(),
+        display: enum_variant
+            .display
+            .as_ref()
+            .map(|display| (**display).to_owned()),
+    })
+}
+
+fn parse_pattern_variant(
+    enum_variant: &EnumVariant,
+    pattern: &SpannedValue<String>,
+) -> DarlingResult<PatternVariant> {
+    let mut errors = DarlingError::accumulator();
+
+    if enum_variant.rename.is_some()
+        || !enum_variant.alias_vec.is_empty()
+        || !enum_variant.deprecated_alias_vec.is_empty()
+        || enum_variant.skip.is_some()
+        || enum_variant.skip_serializing.is_some()
+        || enum_variant.skip_deserializing.is_some()
+        || enum_variant.borrow.is_some()
+        || enum_variant.ascii_case_insensitive.is_some()
+        || enum_variant.display.is_some()
+        || enum_variant.with_span().is_some()
+    {
+        errors.push(spanned_error(
+            pattern.span(),
+            "#[serde(pattern)] cannot be combined with other variant attributes",
+        ));
+    }
+
+    let r#type = match &enum_variant.fields.fields[..] {
+        [r#type] if enum_variant.fields.is_tuple() => Some(r#type.to_owned()),
+        _ => {
+            errors.push(
+                DarlingError::custom("#[serde(pattern)] requires a tuple variant with one type")
+                    .with_span(&enum_variant.ident),
+            );
+            None
+        }
+    };
+
+    let prefix_suffix = pattern
+        .split_once("{}")
+        .filter(|(_, suffix)| !suffix.contains("{}"));
+    if prefix_suffix.is_none() {
+        errors.push(spanned_error(
+            pattern.span(),
+            r#"#[serde(pattern = "...")] must contain exactly one `{}`"#,
+        ));
+    }
+
+    let pattern_variant =
+        r#type
+            .zip(prefix_suffix)
+            .map(|(r#type, (prefix, suffix))| PatternVariant {
+                ident: enum_variant.ident.to_owned(),
+                prefix: prefix.to_owned(),
+                suffix: suffix.to_owned(),
+                r#type,
+            });
+    errors.finish()?;
+
+    Ok(pattern_variant.expect(""))
+}
+
+/// A `#[serde(other)]` variant followed by another one, parsed with `FromStr` before falling back.
+fn parse_fallback_variant(enum_variant: &EnumVariant) -> DarlingResult<PatternVariant> {
+    let mut errors = DarlingError::accumulator();
+
+    if let Some(pattern) = &enum_variant.pattern {
+        errors.push(spanned_error(
+            pattern.span(),
+            "#[serde(pattern)] cannot be combined with #[serde(other)]",
+        ));
+    }
+    if let Some(borrow) = &enum_variant.borrow {
+        errors.push(spanned_error(
+            borrow.span(),
+            "#[serde(borrow)] is only allowed on the last #[serde(other)] variant",
+        ));
+    }
+    if let Some(span) = enum_variant.with_span() {
+        errors.push(spanned_error(span, WITH_ONLY_ON_OTHER));
+    }
+    if let Some(deprecated_alias) = enum_variant.deprecated_alias_vec.first() {
+        errors.push(spanned_error(
+            deprecated_alias.span(),
+            DEPRECATED_ALIAS_ON_OTHER,
+        ));
+    }
+    if let Some(display) = &enum_variant.display {
+        errors.push(spanned_error(
+            display.span(),
+            "#[serde(display)] requires a unit variant",
+        ));
+    }
+
+    let r#type = match &enum_variant.fields.fields[..] {
+        [r#type] if enum_variant.fields.is_tuple() => Some(r#type.to_owned()),
+        _ if enum_variant.fields.is_unit() => {
+            errors.push(
+                DarlingError::custom("only the last #[serde(other)] variant can be a unit variant")
+                    .with_span(&enum_variant.ident),
+            );
+            None
+        }
+        _ => {
+            errors.push(
+                DarlingError::custom("must be a tuple variant with one type")
+                    .with_span(&enum_variant.ident),
+            );
+            None
+        }
+    };
+    errors.finish()?;
+
+    Ok(PatternVariant {
+        ident: enum_variant.ident.to_owned(),
+        prefix: String::new(),
+        suffix: String::new(),
+        r#type: r#type.expect(""),
+    })
+}
+
+fn parse_default_variant(enum_variant: &EnumVariant) -> DarlingResult<DefaultVariant> {
+    if let Some(pattern) = &enum_variant.pattern {
+        return Err(spanned_error(
+            pattern.span(),
+            "#[serde(pattern)] cannot be combined with #[serde(other)]",
+        ));
+    }
+    if let Some(deprecated_alias) = enum_variant.deprecated_alias_vec.first() {
+        return Err(spanned_error(
+            deprecated_alias.span(),
+            DEPRECATED_ALIAS_ON_OTHER,
+        ));
+    }
+
+    if enum_variant.fields.is_tuple() {
+        if let Some(display) = &enum_variant.display {
+            return Err(spanned_error(
+                display.span(),
+                "#[serde(display)] requires a unit variant",
+            ));
+        }
+        if enum_variant.rename.is_some() {
+            return Err(DarlingError::custom(
+                "#[serde(rename)] on the #[serde(other)] variant requires a unit variant",
+            )
+            .with_span(&enum_variant.ident));
+        }
+
+        let r#type = match &enum_variant.fields.fields[..] {
+            [r#type] => r#type.to_owned(),
+            [_, r#type, ..] => {
+                return Err(DarlingError::custom("must be one type").with_span(r#type));
+            }
+            [] => {
+                return Err(DarlingError::custom("must be at least one type")
+                    .with_span(&enum_variant.ident));
+            }
+        };
+
+        let borrowed_lifetimes = match enum_variant
+            .borrow
+            .as_ref()
+            .map(|borrow| (borrow, &**borrow))
+        {
+            Some((borrow, Override::Inherit)) => {
+                let lifetimes = collect_lifetimes(&r#type);
+                if lifetimes.is_empty() {
+                    return Err(spanned_error(
+                        borrow.span(),
+                        "field has no lifetimes to borrow",
+                    ));
+                }
+                lifetimes
+            }
+            Some((borrow, Override::Explicit(lifetimes_str))) => {
+                let lifetimes = Punctuated::<Lifetime, Token![+]>::parse_separated_nonempty
+                    .parse_str(lifetimes_str)
+                    .map_err(|_| {
+                        spanned_error(
+                            borrow.span(),
+                            format!("failed to parse borrowed lifetimes: {lifetimes_str:?}"),
+                        )
+                    })?;
+                let mut errors = DarlingError::accumulator();
+                let type_lifetimes = collect_lifetimes(&r#type);
+                for lifetime in &lifetimes {
+                    if !type_lifetimes.contains(lifetime) {
+                        errors.push(spanned_error(
+                            borrow.span(),
+                            format!("field does not have lifetime {lifetime}"),
+                        ));
+                    }
+                }
+                errors.finish()?;
+                lifetimes.into_iter().collect()
+            }
+            None if is_implicitly_borrowed(&r#type) => collect_lifetimes(&r#type),
+            None => vec![],
+        };
+
+        let (serialize_with, deserialize_with) = parse_with(enum_variant)?;
+
+        Ok(DefaultVariant {
+            ident: enum_variant.ident.to_owned(),
+            discriminant: enum_variant.discriminant.to_owned(),
+            rename: None,
+            display: None,
+            r#type: Some(r#type),
+            serialize_with,
+            deserialize_with,
+            borrowed_lifetimes,
+        })
+    } else if enum_variant.fields.is_unit() {
+        if let Some(borrow) = &enum_variant.borrow {
+            return Err(spanned_error(
+                borrow.span(),
+                "#[serde(borrow)] requires a tuple variant",
+            ));
+        }
+        if let Some(span) = enum_variant.with_span() {
+            return Err(spanned_error(
+                span,
+                "#[serde(with)], #[serde(serialize_with)] and #[serde(deserialize_with)] require a tuple variant",
+            ));
+        }
+        if let Some(VariantRename(Rename::Independent(
+            RenameIndependent::Deserialize(_) | RenameIndependent::Both { .. },
+        ))) = &enum_variant.rename
+        {
+            return Err(DarlingError::custom(
+                "#[serde(rename(deserialize = \"...\"))] cannot be used on a #[serde(other)] variant, it accepts every name",
+            )
+            .with_span(&enum_variant.ident));
+        }
+
+        Ok(DefaultVariant {
+            ident: enum_variant.ident.to_owned(),
+            discriminant: enum_variant.discriminant.to_owned(),
+            rename: enum_variant
+                .rename
+                .as_ref()
+                .and_then(|rename| rename.0.ser_name())
+                .map(ToOwned::to_owned),
+            display: enum_variant
+                .display
+                .as_ref()
+                .map(|display| (**display).to_owned()),
+            r#type: None,
+            serialize_with: None,
+            deserialize_with: None,
+            borrowed_lifetimes: vec![],
+        })
+    } else {
+        Err(DarlingError::custom("must be a tuple or unit variant").with_span(&enum_variant.ident))
+    }
+}
+
+/// `with` is `module::serialize` and `module::deserialize`, like in serde_derive.
+fn parse_with(enum_variant: &EnumVariant) -> DarlingResult<(Option<Path>, Option<Path>)> {
+    let parse_path = |value: &SpannedValue<String>, attr: &str| {
+        syn::parse_str::<Path>(value).map_err(|_| {
+            spanned_error(
+                value.span(),
+                format!(r#"#[serde({attr} = "...")] must be a path"#),
+            )
+        })
+    };
+
+    if let Some(with) = &enum_variant.with {
+        if let Some(span) = enum_variant
+            .serialize_with
+            .as_ref()
+            .or(enum_variant.deserialize_with.as_ref())
+            .map(SpannedValue::span)
+        {
+            return Err(spanned_error(
+                span,
+                "#[serde(with)] cannot be combined with #[serde(serialize_with)] or #[serde(deserialize_with)]",
+            ));
+        }
+        let module = parse_path(with, "with")?;
+        return Ok((
+            Some(syn::parse_quote!(#module::serialize)),
+            Some(syn::parse_quote!(#module::deserialize)),
+        ));
+    }
+
+    let mut errors = DarlingError::accumulator();
+    let serialize_with = enum_variant
+        .serialize_with
+        .as_ref()
+        .and_then(|value| errors.handle(parse_path(value, "serialize_with")));
+    let deserialize_with = enum_variant
+        .deserialize_with
+        .as_ref()
+        .and_then(|value| errors.handle(parse_path(value, "deserialize_with")));
+    errors.finish_with((serialize_with, deserialize_with))
+}
+
+#[derive(FromDeriveInput, Debug)]
+#[darling(attributes(serde), forward_attrs(doc))]
+struct EnumDeriveInput {
+    #[cfg_attr(not(feature = "schemars"), allow(dead_code))]
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    generics: Generics,
+    data: Data<EnumVariant, Ignored>,
+
+    #[darling(default)]
+    rename_all: Option<RenameAll>,
+    #[darling(default)]
+    display_rename_all: Option<SpannedValue<String>>,
+    #[darling(default)]
+    accept_display: Option<bool>,
+    #[darling(default)]
+    char: Option<SpannedValue<bool>>,
+    #[darling(default)]
+    bound: Option<Bound>,
+    #[darling(default, rename = "crate")]
+    crate_: Option<SpannedValue<String>>,
+    #[darling(default)]
+    enum_str_crate: Option<SpannedValue<String>>,
+    #[darling(default)]
+    ascii_case_insensitive: Option<bool>,
+    #[darling(default)]
+    compact_repr: Option<SpannedValue<String>>,
+    #[darling(default)]
+    accept_discriminant: Option<SpannedValue<Override<String>>>,
+    #[darling(default)]
+    remote: Option<SpannedValue<String>>,
+    #[darling(default)]
+    on_deprecated: Option<SpannedValue<String>>,
+    #[darling(default)]
+    str_interop: Option<SpannedValue<bool>>,
+    #[darling(default)]
+    value_enum: Option<SpannedValue<bool>>,
+    #[darling(default)]
+    json_schema: Option<SpannedValue<Override<JsonSchemaOptions>>>,
+    #[darling(default)]
+    sqlx: Option<SpannedValue<bool>>,
+    #[darling(default)]
+    diesel: Option<SpannedValue<bool>>,
+    #[darling(default)]
+    postgres_types: Option<SpannedValue<bool>>,
+}
+
+/// `rename = "..."`, or `rename = 'A'` for `#[serde(char)]`.
+#[derive(Debug)]
+struct VariantRename(Rename);
+
+impl FromMeta for VariantRename {
+    fn from_meta(meta: &Meta) -> DarlingResult<Self> {
+        if let Meta::NameValue(MetaNameValue {
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Char(lit_char),
+                    ..
+                }),
+            ..
+        }) = meta
+        {
+            return Ok(Self(Rename::Normal(lit_char.value().to_string())));
+        }
+        Rename::from_meta(meta).map(Self)
+    }
+}
+
+#[derive(FromMeta, Default, Clone, Debug)]
+#[cfg_attr(not(feature = "schemars"), allow(dead_code))]
+struct JsonSchemaOptions {
+    #[darling(default)]
+    aliases: bool,
+}
+
+const DEPRECATED_ALIAS_ON_OTHER: &str =
+    "#[serde(deprecated_alias)] cannot be used on a #[serde(other)] variant, it accepts every name";
+
+const WITH_ONLY_ON_OTHER: &str = "#[serde(with)], #[serde(serialize_with)] and #[serde(deserialize_with)] are only allowed on the last #[serde(other)] variant";
+
+const INTEGER_TYPES: &[&str] = &["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"];
+
+#[derive(FromVariant, Debug)]
+#[darling(attributes(serde), forward_attrs(doc))]
+struct EnumVariant {
+    #[cfg_attr(not(feature = "schemars"), allow(dead_code))]
+    attrs: Vec<Attribute>,
+    ident: Ident,
+    fields: Fields<Type>,
+    discriminant: Option<Expr>,
+
+    #[darling(default)]
+    rename: Option<VariantRename>,
+    #[darling(default, multiple, rename = "alias")]
+    alias_vec: Vec<Alias>,
+    #[darling(default, multiple, rename = "deprecated_alias")]
+    deprecated_alias_vec: Vec<SpannedValue<String>>,
+    #[darling(default)]
+    skip: Option<bool>,
+    #[darling(default)]
+    skip_serializing: Option<bool>,
+    #[darling(default)]
+    skip_deserializing: Option<bool>,
+    #[darling(default, rename = "other", map = "Self::make_is_other")]
+    is_other: bool,
+    #[darling(default)]
+    pattern: Option<SpannedValue<String>>,
+    #[darling(default)]
+    borrow: Option<SpannedValue<Override<String>>>,
+    #[darling(default)]
+    ascii_case_insensitive: Option<bool>,
+    #[darling(default)]
+    display: Option<SpannedValue<String>>,
+    #[darling(default)]
+    with: Option<SpannedValue<String>>,
+    #[darling(default)]
+    serialize_with: Option<SpannedValue<String>>,
+    #[darling(default)]
+    deserialize_with: Option<SpannedValue<String>>,
+}
+impl EnumVariant {
+    fn make_is_other(v: Option<()>) -> bool {
+        v.is_some()
+    }
+
+    /// The first of `with`, `serialize_with` and `deserialize_with`.
+    fn with_span(&self) -> Option<Span> {
+        self.with
+            .as_ref()
+            .or(self.serialize_with.as_ref())
+            .or(self.deserialize_with.as_ref())
+            .map(SpannedValue::span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_err(input: &str) -> String {
+        match syn::parse_str::<Input>(input) {
+            Ok(_) => panic!("parsed: {input}"),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_name_collisions() {
+        assert_eq!(
+            parse_err(
+                r#"#[serde(rename_all = "snake_case")] enum Foo { FooBar, #[serde(rename = "foo_bar")] Baz }"#
+            ),
+            r#"variant Baz serializes as "foo_bar", the same as variant FooBar"#
+        );
+        assert_eq!(
+            parse_err(r#"enum Foo { A, #[serde(alias = "A")] B }"#),
+            r#"variant B has the deserialize alias "A", which variant A already accepts"#
+        );
+        assert_eq!(
+            parse_err(r#"enum Foo { #[serde(alias = "x", alias = "x")] A }"#),
+            r#"variant A has the deserialize alias "x" more than once"#
+        );
+        assert_eq!(
+            parse_err(
+                r#"enum Foo { #[serde(ascii_case_insensitive)] A, #[serde(rename = "a")] B }"#
+            ),
+            r#"variant B has the deserialize name "a", which variant A already accepts"#
+        );
+        assert_eq!(
+            parse_err(r#"enum Foo { A, #[serde(rename = "B")] C, #[serde(other)] B }"#),
+            r#"variant B serializes as "B", the same as variant C"#
+        );
+
+        assert!(syn::parse_str::<Input>(r#"enum Foo { A, #[serde(rename = "a")] B }"#).is_ok());
+        assert!(
+            syn::parse_str::<Input>(
+                r#"enum Foo { A, #[serde(skip_serializing, rename = "A")] B }"#
+            )
+            .is_err()
+        );
+        assert!(
+            syn::parse_str::<Input>(
+                r#"enum Foo { A, #[serde(skip_deserializing, rename(serialize = "b", deserialize = "A"))] B }"#
+            )
+            .is_ok()
+        );
+
+        assert_eq!(
+            parse_err(r#"#[serde(accept_display)] enum Foo { A, #[serde(display = "A")] B }"#),
+            r#"variant B has the display name "A", which variant A already accepts"#
+        );
+        assert!(syn::parse_str::<Input>(r#"enum Foo { A, #[serde(display = "A")] B }"#).is_ok());
+    }
+}
@@ -0,0 +1,56 @@
+//! Derive macros for [serde-enum-str](https://docs.rs/serde-enum-str), use that crate instead.
+#![allow(clippy::single_match_else)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse_macro_input;
+
+mod bound;
+mod discriminant;
+mod input;
+#[cfg(feature = "clap")]
+mod output_clap;
+mod output_de;
+mod output_interop;
+#[cfg(feature = "schemars")]
+mod output_schemars;
+mod output_ser;
+#[cfg(any(feature = "sqlx", feature = "diesel", feature = "postgres-types"))]
+mod output_sql;
+mod struct_str;
+
+#[proc_macro_derive(Serialize_enum_str, attributes(serde))]
+pub fn derive_serialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as self::input::Input);
+    let input = self::output_ser::InputWrapper(input);
+
+    TokenStream::from(quote!(#input))
+}
+
+#[proc_macro_derive(Deserialize_enum_str, attributes(serde))]
+pub fn derive_deserialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as self::input::Input);
+    let input = self::output_de::InputWrapper(input);
+
+    TokenStream::from(quote!(#input))
+}
+
+/// `Serialize` through `Display`, for ID and code newtypes that are always strings on the wire.
+#[proc_macro_derive(Serialize_struct_str, attributes(serde))]
+pub fn derive_serialize_struct_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as self::struct_str::StructInput);
+
+    TokenStream::from(self::struct_str::impl_serialize(&input))
+}
+
+/// `Deserialize` through `FromStr`, the counterpart of `Serialize_struct_str`.
+#[proc_macro_derive(Deserialize_struct_str, attributes(serde))]
+pub fn derive_deserialize_struct_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as self::struct_str::StructInput);
+
+    TokenStream::from(self::struct_str::impl_deserialize(&input))
+}
@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::input::Input;
+
+/// `clap::ValueEnum` with the serialized names, the deserialize names and aliases as clap aliases,
+/// and `skip_deserializing` variants left out. The `#[serde(deprecated_alias)]` names are only
+/// accepted by `ValueEnum::from_str`.
+///
+/// With `#[serde(pattern)]` or `#[serde(other)]` variants, `ValueEnum::from_str` falls back to
+/// `FromStr` and accepts arbitrary values. clap's `EnumValueParser` only accepts the listed names,
+/// use `#[arg(value_parser = <Foo as FromStr>::from_str)]` to accept the rest on the command line.
+pub fn impl_value_enum(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let clap = quote!(#enum_str_expr::__private::clap);
+    let impl_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let value_variants = input
+        .variants
+        .iter()
+        .filter(|variant| variant.skip_deserializing != Some(true))
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+
+    let possible_values = input
+        .variants
+        .iter()
+        .map(|variant| {
+            let ident = &variant.ident;
+            if variant.skip_deserializing == Some(true) {
+                return quote!(Self::#ident => ::core::option::Option::None,);
+            }
+            let name = input.variant_ser_name(variant);
+            let aliases = input
+                .variant_de_names(variant)
+                .into_iter()
+                .filter(|alias| *alias != name)
+                .collect::<Vec<_>>();
+            let aliases = if aliases.is_empty() {
+                quote!()
+            } else {
+                quote!(.aliases([#(#aliases),*]))
+            };
+            quote! {
+                Self::#ident => ::core::option::Option::Some(
+                    #clap::builder::PossibleValue::new(#name)#aliases,
+                ),
+            }
+        })
+        .collect::<Vec<_>>();
+    let other_possible_values = input
+        .pattern_variants
+        .iter()
+        .map(|pattern_variant| {
+            let ident = &pattern_variant.ident;
+            quote!(Self::#ident(_) => ::core::option::Option::None,)
+        })
+        .chain(input.default_variant.iter().map(|default_variant| {
+            let ident = &default_variant.ident;
+            if default_variant.r#type.is_some() {
+                quote!(Self::#ident(_) => ::core::option::Option::None,)
+            } else {
+                quote!(Self::#ident => ::core::option::Option::None,)
+            }
+        }))
+        .collect::<Vec<_>>();
+
+    // `FromStr` also records the `#[serde(deprecated_alias)]` names, which are not listed.
+    let is_open = !input.pattern_variants.is_empty()
+        || input.default_variant.is_some()
+        || input.has_deprecated_aliases();
+    let from_str = if is_open {
+        quote! {
+            fn from_str(input: &::core::primitive::str, ignore_case: ::core::primitive::bool) -> ::core::result::Result<Self, String> {
+                #enum_str_expr::__private::value_enum_from_str(input, ignore_case)
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl #impl_generics #clap::ValueEnum for #impl_ident #ty_generics #where_clause {
+            fn value_variants<'a>() -> &'a [Self] {
+                &[#(Self::#value_variants),*]
+            }
+
+            #from_str
+
+            fn to_possible_value(&self) -> ::core::option::Option<#clap::builder::PossibleValue> {
+                match *self {
+                    #(#possible_values)*
+                    #(#other_possible_values)*
+                }
+            }
+        }
+    }
+}
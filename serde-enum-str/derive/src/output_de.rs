@@ -0,0 +1,1076 @@
+use alloc::{
+    borrow::ToOwned as _,
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt as _, format_ident, quote};
+use syn::LitByteStr;
+
+use super::{
+    bound::{is_cow_str, is_generic, with_bound, with_lifetime_de, with_where_predicates},
+    discriminant::Tags,
+    input::{AcceptDiscriminant, Input, Variant},
+};
+
+//
+pub struct InputWrapper(pub Input);
+
+impl ToTokens for InputWrapper {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let input = &self.0;
+        let serde_expr = &input.serde_expr;
+
+        //
+        let impl_vis = &input.vis;
+        let impl_ident = &input.ident;
+        let target = input.target();
+
+        //
+        let variant_names = input
+            .variants
+            .iter()
+            .filter(|variant| variant.skip_deserializing != Some(true))
+            .map(|variant| input.variant_de_name(variant))
+            .collect::<Vec<_>>();
+
+        let (str_v, str_arms) = name_match(input, quote!(v), false, false);
+        let (bytes_v, bytes_arms) = name_match(input, quote!(v), true, false);
+
+        // `"2"` for the variant with discriminant `2`, before the `#[serde(other)]` variant.
+        let with_numeric_str = |value: TokenStream, fallback: TokenStream| {
+            if input.accept_discriminant == Some(AcceptDiscriminant::IntegerOrNumericString) {
+                quote! {
+                    match Self::from_numeric_str(#value) {
+                        ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+                        ::core::option::Option::None => #fallback,
+                    }
+                }
+            } else {
+                fallback
+            }
+        };
+        // `#[serde(pattern)]` variants in declaration order, before the `#[serde(other)]` variant.
+        let with_pattern_variants = |value: TokenStream, fallback: TokenStream| {
+            if input.pattern_variants.is_empty() {
+                return fallback;
+            }
+            let pattern_variants = input.pattern_variants.iter().map(|pattern_variant| {
+                let ident = &pattern_variant.ident;
+                let prefix = &pattern_variant.prefix;
+                let suffix = &pattern_variant.suffix;
+                quote! {
+                    if let ::core::option::Option::Some(inner) = ::core::primitive::str::strip_prefix(#value, #prefix)
+                        .and_then(|v| ::core::primitive::str::strip_suffix(v, #suffix))
+                    {
+                        if let ::core::result::Result::Ok(inner) = ::core::str::FromStr::from_str(inner) {
+                            return ::core::result::Result::Ok(#target::#ident(inner));
+                        }
+                    }
+                }
+            });
+            quote! {
+                {
+                    #(#pattern_variants)*
+                    #fallback
+                }
+            }
+        };
+        // What to do with a string that matches none of the variants.
+        let fallback = |deserializer: TokenStream, value: TokenStream| {
+            let fallback = if let Some(default_variant) = &input.default_variant {
+                let ident = &default_variant.ident;
+                if default_variant.r#type.is_some() {
+                    let deserialize = deserialize_payload(input);
+                    quote! {
+                        #deserialize(#deserializer)
+                            .map(#target::#ident)
+                    }
+                } else {
+                    quote! {
+                        ::core::result::Result::Ok(#target::#ident)
+                    }
+                }
+            } else {
+                quote! {
+                    ::core::result::Result::Err(#serde_expr::de::Error::unknown_variant(#value, VARIANTS))
+                }
+            };
+            let fallback = with_pattern_variants(value.to_owned(), fallback);
+            with_numeric_str(value, fallback)
+        };
+        let has_default_variant_type = input
+            .default_variant
+            .as_ref()
+            .map(|default_variant| default_variant.r#type.is_some())
+            == Some(true);
+
+        let visit_str_fallback = fallback(
+            quote!(#serde_expr::de::value::StrDeserializer::<__E>::new(v)),
+            quote!(v),
+        );
+        let visit_borrowed_str = if has_default_variant_type {
+            let fallback = match &input.default_variant {
+                // Like `#[serde(borrow)] Cow<'a, str>` in serde_derive, which would be owned otherwise.
+                Some(default_variant)
+                    if !default_variant.borrowed_lifetimes.is_empty()
+                        && default_variant.deserialize_with.is_none()
+                        && default_variant.r#type.as_ref().map(is_cow_str) == Some(true) =>
+                {
+                    let ident = &default_variant.ident;
+                    with_numeric_str(
+                        quote!(v),
+                        with_pattern_variants(
+                            quote!(v),
+                            quote! {
+                                ::core::result::Result::Ok(#target::#ident(::core::convert::From::from(v)))
+                            },
+                        ),
+                    )
+                }
+                _ => fallback(
+                    quote!(#serde_expr::de::value::BorrowedStrDeserializer::<__E>::new(v)),
+                    quote!(v),
+                ),
+            };
+            quote! {
+                fn visit_borrowed_str<__E>(self, v: &'de ::core::primitive::str) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: #serde_expr::de::Error,
+                {
+                    match #str_v {
+                        #(#str_arms)*
+                        _ => #fallback,
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
+        // Like `visit_borrowed_str`, for formats that send the names as bytes.
+        let visit_borrowed_bytes = if has_default_variant_type {
+            quote! {
+                fn visit_borrowed_bytes<__E>(self, v: &'de [::core::primitive::u8]) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: #serde_expr::de::Error,
+                {
+                    match #bytes_v {
+                        #(#bytes_arms)*
+                        _ => match ::core::str::from_utf8(v) {
+                            ::core::result::Result::Ok(s) => self.visit_borrowed_str(s),
+                            ::core::result::Result::Err(_) => ::core::result::Result::Err(
+                                #serde_expr::de::Error::invalid_value(#serde_expr::de::Unexpected::Bytes(v), &self),
+                            ),
+                        },
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
+        let visit_string = if has_default_variant_type {
+            let fallback = fallback(
+                quote!(#serde_expr::de::value::StringDeserializer::<__E>::new(v)),
+                quote!(&v),
+            );
+            let (string_v, string_arms) = name_match(input, quote!(v.as_str()), false, false);
+            quote! {
+                fn visit_string<__E>(self, v: String) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: #serde_expr::de::Error,
+                {
+                    match #string_v {
+                        #(#string_arms)*
+                        _ => #fallback,
+                    }
+                }
+            }
+        } else {
+            quote!()
+        };
+        // `'A'`, and `"A"` through `visit_str`.
+        let visit_char = if input.char {
+            quote! {
+                fn visit_char<__E>(self, v: ::core::primitive::char) -> ::core::result::Result<Self::Value, __E>
+                where
+                    __E: #serde_expr::de::Error,
+                {
+                    self.visit_str(::core::primitive::char::encode_utf8(v, &mut [0; 4]))
+                }
+            }
+        } else {
+            quote!()
+        };
+        // Numbers and booleans for a `#[serde(other)]` payload like `Code(u16)`.
+        let visit_payload = |value: TokenStream, ty: TokenStream| {
+            let default_variant = input.default_variant.as_ref()?;
+            default_variant.r#type.as_ref()?;
+            let ident = &default_variant.ident;
+            let deserialize = deserialize_payload(input);
+            Some(quote! {
+                #deserialize(<#ty as #serde_expr::de::IntoDeserializer<'de, __E>>::into_deserializer(#value))
+                    .map(#target::#ident)
+            })
+        };
+        let visit_payload_primitives = |types: &[&str]| {
+            if !has_default_variant_type {
+                return quote!();
+            }
+            let methods = types.iter().map(|ty| {
+                let method = format_ident!("visit_{}", ty);
+                let ty = format_ident!("{}", ty);
+                let body = visit_payload(quote!(v), quote!(::core::primitive::#ty));
+                quote! {
+                    fn #method<__E>(self, v: ::core::primitive::#ty) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: #serde_expr::de::Error,
+                    {
+                        #body
+                    }
+                }
+            });
+            quote!(#(#methods)*)
+        };
+        let visit_bytes_fallback = fallback(
+            quote!(#serde_expr::de::value::StrDeserializer::<__E>::new(s)),
+            quote!(s),
+        );
+
+        //
+        let pattern_variant_types = input
+            .pattern_variants
+            .iter()
+            .map(|pattern_variant| &pattern_variant.r#type);
+        let (_, ty_generics, _) = input.generics.split_for_impl();
+        let borrowed_lifetimes = input.borrowed_lifetimes();
+        let deserialize_generics = with_lifetime_de(&input.generics, borrowed_lifetimes);
+        let deserialize_generics =
+            match input.bound.as_ref().and_then(|bound| bound.de_predicates()) {
+                Some(predicates) => with_where_predicates(&deserialize_generics, predicates),
+                None => with_bound(
+                    &with_bound(
+                        &deserialize_generics,
+                        input
+                            .default_variant
+                            .iter()
+                            .filter(|default_variant| default_variant.deserialize_with.is_none())
+                            .filter_map(|default_variant| default_variant.r#type.as_ref()),
+                        &quote!(#serde_expr::Deserialize<'de>),
+                    ),
+                    pattern_variant_types.clone(),
+                    &quote!(::core::str::FromStr),
+                ),
+            };
+        let (impl_generics, visitor_ty_generics, where_clause) =
+            deserialize_generics.split_for_impl();
+
+        // Integers, as written by `Serialize` for formats that are not human readable, or accepted
+        // with `#[serde(accept_discriminant)]`.
+        let tag_repr = input
+            .compact_repr
+            .to_owned()
+            .or_else(|| input.accept_discriminant.map(|_| format_ident!("i64")));
+        let (tag_consts, tag_visitor, tag_from_tag) = if let Some(repr) = &tag_repr {
+            let tags = Tags::new(input, repr);
+            let option_arms = tags.option_arms(input);
+
+            // A number that is not a tag goes to the `#[serde(other)]` payload, if any.
+            let unknown_tag = |unexpected: TokenStream, payload: Option<TokenStream>| match (
+                &input.default_variant,
+                payload,
+            ) {
+                (Some(default_variant), _) if default_variant.r#type.is_none() => {
+                    let ident = &default_variant.ident;
+                    quote!(::core::result::Result::Ok(#target::#ident))
+                }
+                (Some(_), Some(payload)) => payload,
+                _ => quote! {
+                    ::core::result::Result::Err(#serde_expr::de::Error::invalid_value(#unexpected, &self))
+                },
+            };
+            let unknown_u64 = unknown_tag(
+                quote!(#serde_expr::de::Unexpected::Unsigned(v)),
+                visit_payload(quote!(v), quote!(::core::primitive::u64)),
+            );
+            let unknown_i64 = unknown_tag(
+                quote!(#serde_expr::de::Unexpected::Signed(v)),
+                visit_payload(quote!(v), quote!(::core::primitive::i64)),
+            );
+            let unknown_seq_tag = unknown_tag(
+                if repr.to_string().starts_with('i') {
+                    quote!(#serde_expr::de::Unexpected::Signed(::core::convert::From::from(tag)))
+                } else {
+                    quote!(#serde_expr::de::Unexpected::Unsigned(::core::convert::From::from(tag)))
+                },
+                None,
+            );
+
+            let visit_payload_f64_bool = visit_payload_primitives(&["f64", "bool"]);
+
+            let visit_seq = match (
+                &input.compact_repr,
+                &input.default_variant,
+                &tags.default_variant_tag,
+            ) {
+                (Some(_), Some(default_variant), Some(tag)) if default_variant.r#type.is_some() => {
+                    let ident = &default_variant.ident;
+                    quote! {
+                        fn visit_seq<__A>(self, mut seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                        where
+                            __A: #serde_expr::de::SeqAccess<'de>,
+                        {
+                            let tag: ::core::primitive::#repr = match #serde_expr::de::SeqAccess::next_element(&mut seq)? {
+                                ::core::option::Option::Some(tag) => tag,
+                                ::core::option::Option::None => {
+                                    return ::core::result::Result::Err(#serde_expr::de::Error::invalid_length(0, &self));
+                                }
+                            };
+                            if tag == #tag {
+                                return match #serde_expr::de::SeqAccess::next_element(&mut seq)? {
+                                    ::core::option::Option::Some(v) => ::core::result::Result::Ok(#target::#ident(v)),
+                                    ::core::option::Option::None => {
+                                        ::core::result::Result::Err(#serde_expr::de::Error::invalid_length(1, &self))
+                                    }
+                                };
+                            }
+                            match Self::from_tag(tag) {
+                                ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+                                ::core::option::Option::None => #unknown_seq_tag,
+                            }
+                        }
+                    }
+                }
+                _ => quote!(),
+            };
+
+            (
+                tags.consts,
+                quote! {
+                    fn visit_u64<__E>(self, v: ::core::primitive::u64) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: #serde_expr::de::Error,
+                    {
+                        match ::core::convert::TryFrom::try_from(v).ok().and_then(Self::from_tag) {
+                            ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+                            ::core::option::Option::None => #unknown_u64,
+                        }
+                    }
+
+                    fn visit_i64<__E>(self, v: ::core::primitive::i64) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: #serde_expr::de::Error,
+                    {
+                        match ::core::convert::TryFrom::try_from(v).ok().and_then(Self::from_tag) {
+                            ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+                            ::core::option::Option::None => #unknown_i64,
+                        }
+                    }
+
+                    #visit_payload_f64_bool
+
+                    #visit_seq
+                },
+                quote! {
+                    impl #impl_generics __Visitor #visitor_ty_generics #where_clause {
+                        fn from_tag(tag: ::core::primitive::#repr) -> ::core::option::Option<#target #ty_generics> {
+                            match tag {
+                                #(#option_arms)*
+                                _ => ::core::option::Option::None,
+                            }
+                        }
+
+                        #[allow(dead_code)]
+                        fn from_numeric_str(v: &::core::primitive::str) -> ::core::option::Option<#target #ty_generics> {
+                            ::core::str::FromStr::from_str(v).ok().and_then(Self::from_tag)
+                        }
+                    }
+                },
+            )
+        } else {
+            (
+                quote!(),
+                visit_payload_primitives(&["u64", "i64", "f64", "bool"]),
+                quote!(),
+            )
+        };
+
+        // Like the untagged enum before, a payload like `Code(u16)` also takes numbers.
+        let deserialize_text = if input.accept_discriminant.is_some() || has_default_variant_type {
+            quote!(#serde_expr::Deserializer::deserialize_any(deserializer, visitor))
+        } else if input.char {
+            quote!(#serde_expr::Deserializer::deserialize_char(deserializer, visitor))
+        } else {
+            quote!(#serde_expr::Deserializer::deserialize_str(deserializer, visitor))
+        };
+        let deserialize_call = if let Some(repr) = &input.compact_repr {
+            let deserialize_binary = if has_default_variant_type {
+                quote!(#serde_expr::Deserializer::deserialize_tuple(deserializer, 2, visitor))
+            } else {
+                let deserialize_repr = format_ident!("deserialize_{}", repr);
+                quote!(#serde_expr::Deserializer::#deserialize_repr(deserializer, visitor))
+            };
+            quote! {
+                if #serde_expr::Deserializer::is_human_readable(&deserializer) {
+                    #deserialize_text
+                } else {
+                    #deserialize_binary
+                }
+            }
+        } else {
+            deserialize_text
+        };
+
+        let deserialize_body = quote! {
+                    #[allow(dead_code)]
+                    const VARIANTS: &[&::core::primitive::str] = &[#(#variant_names),*];
+
+                    #tag_consts
+
+                    struct __Visitor #impl_generics #where_clause {
+                        marker: ::core::marker::PhantomData<#target #ty_generics>,
+                        lifetime: ::core::marker::PhantomData<&'de ()>,
+                    }
+
+                    impl #impl_generics #serde_expr::de::Visitor<'de> for __Visitor #visitor_ty_generics #where_clause {
+                        type Value = #target #ty_generics;
+
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.write_str("variant identifier")
+                        }
+
+                        fn visit_str<__E>(self, v: &::core::primitive::str) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: #serde_expr::de::Error,
+                        {
+                            match #str_v {
+                                #(#str_arms)*
+                                _ => #visit_str_fallback,
+                            }
+                        }
+
+                        #visit_borrowed_str
+
+                        #visit_string
+
+                        #visit_char
+
+                        #visit_borrowed_bytes
+
+                        fn visit_bytes<__E>(self, v: &[::core::primitive::u8]) -> ::core::result::Result<Self::Value, __E>
+                        where
+                            __E: #serde_expr::de::Error,
+                        {
+                            match #bytes_v {
+                                #(#bytes_arms)*
+                                _ => match ::core::str::from_utf8(v) {
+                                    ::core::result::Result::Ok(s) => #visit_bytes_fallback,
+                                    ::core::result::Result::Err(_) => ::core::result::Result::Err(
+                                        #serde_expr::de::Error::invalid_value(#serde_expr::de::Unexpected::Bytes(v), &self),
+                                    ),
+                                },
+                            }
+                        }
+
+                        #tag_visitor
+                    }
+
+                    #tag_from_tag
+
+                    let visitor = __Visitor {
+                        marker: ::core::marker::PhantomData,
+                        lifetime: ::core::marker::PhantomData,
+                    };
+                    #deserialize_call
+        };
+
+        // Like serde's remote derive, for `#[serde(deserialize_with = "FooDef::deserialize")]`.
+        let token = if input.remote.is_some() {
+            quote! {
+                impl #impl_generics #impl_ident #ty_generics #where_clause {
+                    #impl_vis fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<#target #ty_generics, __D::Error>
+                    where __D: #serde_expr::Deserializer<'de>
+                    {
+                        #deserialize_body
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics #serde_expr::Deserialize<'de> for #impl_ident #ty_generics #where_clause {
+                    fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                    where __D: #serde_expr::Deserializer<'de>
+                    {
+                        #deserialize_body
+                    }
+                }
+            }
+        };
+        tokens.append_all(token);
+
+        //
+        if input.has_deprecated_aliases() {
+            let enum_name = input.target_name();
+            let on_deprecated = input
+                .on_deprecated
+                .as_ref()
+                .map(|on_deprecated| quote!(#on_deprecated(#enum_name, alias);));
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+            let token = quote! {
+                impl #impl_generics #impl_ident #ty_generics #where_clause {
+                    /// How many times a `#[serde(deprecated_alias)]` name was deserialized or parsed.
+                    #impl_vis fn deprecated_alias_hits() -> ::core::primitive::usize {
+                        ::core::sync::atomic::AtomicUsize::load(
+                            Self::__deprecated_alias_counter(),
+                            ::core::sync::atomic::Ordering::Relaxed,
+                        )
+                    }
+
+                    fn __deprecated_alias_counter() -> &'static ::core::sync::atomic::AtomicUsize {
+                        static HITS: ::core::sync::atomic::AtomicUsize = ::core::sync::atomic::AtomicUsize::new(0);
+                        &HITS
+                    }
+
+                    fn __deprecated_alias_hit(alias: &'static ::core::primitive::str) {
+                        ::core::sync::atomic::AtomicUsize::fetch_add(
+                            Self::__deprecated_alias_counter(),
+                            1,
+                            ::core::sync::atomic::Ordering::Relaxed,
+                        );
+                        #on_deprecated
+                    }
+                }
+            };
+            tokens.append_all(token);
+        }
+
+        #[cfg(feature = "clap")]
+        if input.value_enum {
+            tokens.append_all(super::output_clap::impl_value_enum(input));
+        }
+
+        #[cfg(feature = "sqlx")]
+        if input.sqlx {
+            tokens.append_all(super::output_sql::impl_sqlx_decode(input));
+        }
+
+        #[cfg(feature = "diesel")]
+        if input.diesel {
+            tokens.append_all(super::output_sql::impl_diesel_from_sql(input));
+        }
+
+        #[cfg(feature = "postgres-types")]
+        if input.postgres_types {
+            tokens.append_all(super::output_sql::impl_postgres_from_sql(input));
+        }
+
+        //
+        let enum_str_expr = &input.enum_str_expr;
+        let enum_name = input.target_name();
+        let parse_enum_error = quote! {
+            #enum_str_expr::ParseEnumError::new(value, #enum_name, VARIANTS)
+        };
+        let parse_bytes_error = quote! {
+            #enum_str_expr::ParseEnumError::from_bytes(value, #enum_name, VARIANTS)
+        };
+
+        //
+        // The orphan rule only allows inherent functions for `#[serde(remote)]`.
+        if input.has_explicit_discriminant()
+            && input.pattern_variants.is_empty()
+            && input.remote.is_none()
+        {
+            let tags = Tags::new(input, &format_ident!("i64"));
+            let consts = &tags.consts;
+            let option_arms = tags.option_arms(input);
+            let unknown_tag = match &input.default_variant {
+                Some(default_variant) if default_variant.r#type.is_none() => {
+                    let ident = &default_variant.ident;
+                    quote!(::core::result::Result::Ok(#target::#ident))
+                }
+                _ => quote! {
+                    ::core::result::Result::Err(#enum_str_expr::TryFromDiscriminantError::new(value, #enum_name))
+                },
+            };
+            let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+
+            let token = quote! {
+                impl #impl_generics ::core::convert::TryFrom<::core::primitive::i64> for #impl_ident #ty_generics #where_clause {
+                    type Error = #enum_str_expr::TryFromDiscriminantError;
+
+                    fn try_from(value: ::core::primitive::i64) -> ::core::result::Result<Self, #enum_str_expr::TryFromDiscriminantError> {
+                        #consts
+
+                        match value {
+                            #(#option_arms)*
+                            _ => ::core::option::Option::None,
+                        }
+                        .map_or_else(|| #unknown_tag, ::core::result::Result::Ok)
+                    }
+                }
+            };
+            tokens.append_all(token);
+        }
+
+        // A borrowed payload cannot come from `FromStr`, only from a `&'de str` that outlives it,
+        // with the generics of `Deserialize`. A `char` is parsed like an owned string.
+        if !borrowed_lifetimes.is_empty() {
+            let deserialize = if input.remote.is_some() {
+                quote!(Self::deserialize)
+            } else {
+                quote!(<Self as #serde_expr::Deserialize<'de>>::deserialize)
+            };
+            let from_str_body = quote! {
+                const VARIANTS: &[&::core::primitive::str] = &[#(#variant_names),*];
+
+                #deserialize(#serde_expr::de::value::BorrowedStrDeserializer::<#serde_expr::de::value::Error>::new(value))
+                    .map_err(|err| #parse_enum_error.with_reason(err))
+            };
+            let from_bytes_body = quote! {
+                const VARIANTS: &[&::core::primitive::str] = &[#(#variant_names),*];
+
+                #deserialize(#serde_expr::de::value::BorrowedBytesDeserializer::<#serde_expr::de::value::Error>::new(value))
+                    .map_err(|err| #parse_bytes_error.with_reason(err))
+            };
+            let from_char_body = quote! {
+                const VARIANTS: &[&::core::primitive::str] = &[#(#variant_names),*];
+
+                let mut buf = [0; 4];
+                let value = ::core::primitive::char::encode_utf8(value, &mut buf);
+                #deserialize(#serde_expr::de::value::StrDeserializer::<#serde_expr::de::value::Error>::new(value))
+                    .map_err(|err| #parse_enum_error.with_reason(err))
+            };
+
+            if input.remote.is_some() {
+                let from_char = if input.char {
+                    quote! {
+                        /// Like `TryFrom<char>`.
+                        #impl_vis fn from_char(value: ::core::primitive::char) -> ::core::result::Result<#target #ty_generics, #enum_str_expr::ParseEnumError> {
+                            #from_char_body
+                        }
+                    }
+                } else {
+                    quote!()
+                };
+                let token = quote! {
+                    impl #impl_generics #impl_ident #ty_generics #where_clause {
+                        /// Like `TryFrom<&str>`, borrowing the `#[serde(other)]` payload.
+                        #impl_vis fn from_str(value: &'de ::core::primitive::str) -> ::core::result::Result<#target #ty_generics, #enum_str_expr::ParseEnumError> {
+                            #from_str_body
+                        }
+
+                        /// Like `from_str`.
+                        #impl_vis fn from_bytes(value: &'de [::core::primitive::u8]) -> ::core::result::Result<#target #ty_generics, #enum_str_expr::ParseEnumError> {
+                            #from_bytes_body
+                        }
+
+                        #from_char
+                    }
+                };
+                tokens.append_all(token);
+
+                return;
+            }
+
+            let token = quote! {
+                impl #impl_generics ::core::convert::TryFrom<&'de ::core::primitive::str> for #impl_ident #ty_generics #where_clause {
+                    type Error = #enum_str_expr::ParseEnumError;
+
+                    fn try_from(value: &'de ::core::primitive::str) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                        #from_str_body
+                    }
+                }
+
+                impl #impl_generics #impl_ident #ty_generics #where_clause {
+                    /// Like `TryFrom<&str>`, borrowing the `#[serde(other)]` payload.
+                    #impl_vis fn from_bytes(value: &'de [::core::primitive::u8]) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                        #from_bytes_body
+                    }
+                }
+
+                impl #impl_generics ::core::convert::TryFrom<&'de [::core::primitive::u8]> for #impl_ident #ty_generics #where_clause {
+                    type Error = #enum_str_expr::ParseEnumError;
+
+                    fn try_from(value: &'de [::core::primitive::u8]) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                        Self::from_bytes(value)
+                    }
+                }
+            };
+            tokens.append_all(token);
+
+            if input.char {
+                let token = quote! {
+                    impl #impl_generics ::core::convert::TryFrom<::core::primitive::char> for #impl_ident #ty_generics #where_clause {
+                        type Error = #enum_str_expr::ParseEnumError;
+
+                        fn try_from(value: ::core::primitive::char) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                            #from_char_body
+                        }
+                    }
+                };
+                tokens.append_all(token);
+            }
+
+            return;
+        }
+
+        //
+        let from_str_fallback = if let Some(default_variant) = &input.default_variant {
+            let ident = &default_variant.ident;
+            if default_variant.r#type.is_some() {
+                let deserialize = deserialize_payload(input);
+                quote! {
+                    #deserialize(
+                        #enum_str_expr::__private::ParseStrDeserializer::<#serde_expr::de::value::Error>::new(value),
+                    )
+                    .map(#target::#ident)
+                    .map_err(|err| #parse_enum_error.with_reason(err))
+                }
+            } else {
+                quote! {
+                    ::core::result::Result::Ok(#target::#ident)
+                }
+            }
+        } else {
+            quote! {
+                ::core::result::Result::Err(#parse_enum_error)
+            }
+        };
+
+        let from_str_fallback = with_pattern_variants(quote!(value), from_str_fallback);
+        let from_str_fallback = if input.accept_discriminant
+            == Some(AcceptDiscriminant::IntegerOrNumericString)
+            && let Some(repr) = &tag_repr
+        {
+            let tags = Tags::new(input, repr);
+            let consts = &tags.consts;
+            let option_arms = tags.option_arms(input);
+            quote! {
+                {
+                    #consts
+
+                    let tag = ::core::str::FromStr::from_str(value).ok();
+                    match tag.and_then(|tag: ::core::primitive::#repr| match tag {
+                        #(#option_arms)*
+                        _ => ::core::option::Option::None,
+                    }) {
+                        ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+                        ::core::option::Option::None => #from_str_fallback,
+                    }
+                }
+            }
+        } else {
+            from_str_fallback
+        };
+
+        let from_str_generics = if is_generic(&input.generics)
+            && let Some(default_variant) = &input.default_variant
+            && default_variant.deserialize_with.is_none()
+            && let Some(r#type) = &default_variant.r#type
+        {
+            with_where_predicates(
+                &input.generics,
+                &[syn::parse_quote!(#r#type: #serde_expr::de::DeserializeOwned)],
+            )
+        } else {
+            input.generics.to_owned()
+        };
+        let from_str_generics = with_bound(
+            &from_str_generics,
+            pattern_variant_types,
+            &quote!(::core::str::FromStr),
+        );
+        let (impl_generics, _, where_clause) = from_str_generics.split_for_impl();
+
+        let (from_str_value, from_str_arms) =
+            name_match(input, quote!(value), false, input.accept_display);
+        let from_str_body = quote! {
+            #[allow(dead_code)]
+            const VARIANTS: &[&::core::primitive::str] = &[#(#variant_names),*];
+
+            match #from_str_value {
+                #(#from_str_arms)*
+                _ => #from_str_fallback,
+            }
+        };
+
+        // The names are matched as bytes, only the fallback needs UTF-8.
+        let from_str_call = if input.remote.is_some() {
+            quote!(Self::from_str(value))
+        } else {
+            quote!(<Self as ::core::str::FromStr>::from_str(value))
+        };
+        let (from_bytes_value, from_bytes_arms) = name_match(input, quote!(value), true, false);
+        let from_bytes_body = quote! {
+            #[allow(dead_code)]
+            const VARIANTS: &[&::core::primitive::str] = &[#(#variant_names),*];
+
+            match #from_bytes_value {
+                #(#from_bytes_arms)*
+                _ => match ::core::str::from_utf8(value) {
+                    ::core::result::Result::Ok(value) => #from_str_call,
+                    ::core::result::Result::Err(err) => ::core::result::Result::Err(#parse_bytes_error.with_reason(err)),
+                },
+            }
+        };
+
+        if input.remote.is_some() {
+            let from_char = if input.char {
+                quote! {
+                    /// Like `TryFrom<char>`.
+                    #impl_vis fn from_char(value: ::core::primitive::char) -> ::core::result::Result<#target #ty_generics, #enum_str_expr::ParseEnumError> {
+                        Self::from_str(::core::primitive::char::encode_utf8(value, &mut [0; 4]))
+                    }
+                }
+            } else {
+                quote!()
+            };
+            let token = quote! {
+                impl #impl_generics #impl_ident #ty_generics #where_clause {
+                    /// Like `FromStr::from_str`.
+                    #impl_vis fn from_str(value: &::core::primitive::str) -> ::core::result::Result<#target #ty_generics, #enum_str_expr::ParseEnumError> {
+                        #from_str_body
+                    }
+
+                    /// Like `from_str`, without validating the UTF-8 of the variant names.
+                    #impl_vis fn from_bytes(value: &[::core::primitive::u8]) -> ::core::result::Result<#target #ty_generics, #enum_str_expr::ParseEnumError> {
+                        #from_bytes_body
+                    }
+
+                    #from_char
+                }
+            };
+            tokens.append_all(token);
+
+            return;
+        }
+
+        //
+        let token = quote! {
+            impl #impl_generics ::core::str::FromStr for #impl_ident #ty_generics #where_clause {
+                type Err = #enum_str_expr::ParseEnumError;
+
+                fn from_str(value: &::core::primitive::str) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                    #from_str_body
+                }
+            }
+        };
+        tokens.append_all(token);
+
+        //
+        let token = if input.str_interop && input.parses_every_str() {
+            // `TryFrom` comes from the blanket impl, with `Infallible`.
+            quote! {
+                impl #impl_generics ::core::convert::From<String> for #impl_ident #ty_generics #where_clause {
+                    fn from(value: String) -> Self {
+                        ::core::convert::From::from(value.as_str())
+                    }
+                }
+
+                impl #impl_generics ::core::convert::From<&::core::primitive::str> for #impl_ident #ty_generics #where_clause {
+                    fn from(value: &::core::primitive::str) -> Self {
+                        match value.parse() {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(_) => ::core::unreachable!(),
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics ::core::convert::TryFrom<String> for #impl_ident #ty_generics #where_clause {
+                    type Error = #enum_str_expr::ParseEnumError;
+
+                    fn try_from(value: String) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                        value.parse()
+                    }
+                }
+
+                impl #impl_generics ::core::convert::TryFrom<&::core::primitive::str> for #impl_ident #ty_generics #where_clause {
+                    type Error = #enum_str_expr::ParseEnumError;
+
+                    fn try_from(value: &::core::primitive::str) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                        value.parse()
+                    }
+                }
+            }
+        };
+        tokens.append_all(token);
+
+        //
+        let token = quote! {
+            impl #impl_generics #impl_ident #ty_generics #where_clause {
+                /// Like `FromStr::from_str`, without validating the UTF-8 of the variant names.
+                #impl_vis fn from_bytes(value: &[::core::primitive::u8]) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                    #from_bytes_body
+                }
+            }
+
+            impl #impl_generics ::core::convert::TryFrom<&[::core::primitive::u8]> for #impl_ident #ty_generics #where_clause {
+                type Error = #enum_str_expr::ParseEnumError;
+
+                fn try_from(value: &[::core::primitive::u8]) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                    Self::from_bytes(value)
+                }
+            }
+        };
+        tokens.append_all(token);
+
+        //
+        if input.char {
+            let token = quote! {
+                impl #impl_generics ::core::convert::TryFrom<::core::primitive::char> for #impl_ident #ty_generics #where_clause {
+                    type Error = #enum_str_expr::ParseEnumError;
+
+                    fn try_from(value: ::core::primitive::char) -> ::core::result::Result<Self, #enum_str_expr::ParseEnumError> {
+                        ::core::primitive::char::encode_utf8(value, &mut [0; 4]).parse()
+                    }
+                }
+            };
+            tokens.append_all(token);
+        }
+    }
+}
+
+/// The function reading the `#[serde(other)]` payload, `#[serde(deserialize_with)]` or `Deserialize`.
+fn deserialize_payload(input: &Input) -> TokenStream {
+    let serde_expr = &input.serde_expr;
+    match input
+        .default_variant
+        .as_ref()
+        .and_then(|default_variant| default_variant.deserialize_with.as_ref())
+    {
+        Some(deserialize_with) => quote!(#deserialize_with),
+        None => quote!(#serde_expr::Deserialize::deserialize),
+    }
+}
+
+/// Above this many names, `name_match` matches byte by byte.
+const MATCH_TREE_THRESHOLD: usize = 16;
+
+/// The scrutinee and the match arms for the deserializable variants, against `&str` or `&[u8]`,
+/// with the `Display` names too for `#[serde(accept_display)]`.
+///
+/// Large enums are matched as byte slice patterns, which rustc turns into a switch on the length
+/// and then on each byte, instead of comparing the names one by one.
+///
+/// A `#[serde(deprecated_alias)]` gets an arm of its own, which records the hit first.
+fn name_match(
+    input: &Input,
+    value: TokenStream,
+    bytes: bool,
+    display: bool,
+) -> (TokenStream, Vec<TokenStream>) {
+    let target = input.target();
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    // The names of a variant, then each deprecated alias on its own.
+    let groups = input
+        .variants
+        .iter()
+        .filter(|variant| variant.skip_deserializing != Some(true))
+        .flat_map(|variant| {
+            let mut names = input.variant_de_names(variant);
+            if display {
+                let display_name = input.variant_display_name(variant);
+                if !names.contains(&display_name)
+                    && !variant.deprecated_alias_vec.contains(&display_name)
+                {
+                    names.push(display_name);
+                }
+            }
+            core::iter::once((variant, names, None)).chain(variant.deprecated_alias_vec.iter().map(
+                move |deprecated_alias| {
+                    (
+                        variant,
+                        vec![deprecated_alias.to_owned()],
+                        Some(deprecated_alias),
+                    )
+                },
+            ))
+        })
+        .collect::<Vec<_>>();
+    let result = |variant: &Variant, deprecated_alias: Option<&String>| {
+        let ident = &variant.ident;
+        match deprecated_alias {
+            Some(deprecated_alias) => quote! {
+                {
+                    <#impl_ident #ty_generics>::__deprecated_alias_hit(#deprecated_alias);
+                    ::core::result::Result::Ok(#target::#ident)
+                }
+            },
+            None => quote!(::core::result::Result::Ok(#target::#ident)),
+        }
+    };
+
+    if groups
+        .iter()
+        .map(|(_, names, _)| names.len())
+        .sum::<usize>()
+        > MATCH_TREE_THRESHOLD
+    {
+        let value = if bytes {
+            value
+        } else {
+            quote!(::core::primitive::str::as_bytes(#value))
+        };
+        let arms = groups
+            .iter()
+            .map(|(variant, names, deprecated_alias)| {
+                let patterns = names.iter().map(|name| {
+                    let bytes = name.as_bytes().iter().map(|byte| {
+                        if variant.ascii_case_insensitive && byte.is_ascii_alphabetic() {
+                            let lower = byte.to_ascii_lowercase();
+                            let upper = byte.to_ascii_uppercase();
+                            quote!(#lower | #upper)
+                        } else {
+                            quote!(#byte)
+                        }
+                    });
+                    quote!([#(#bytes),*])
+                });
+                let result = result(variant, *deprecated_alias);
+                quote! {
+                    #(#patterns)|* => #result,
+                }
+            })
+            .collect();
+        return (value, arms);
+    }
+
+    let arms = groups
+        .iter()
+        .map(|(variant, names, deprecated_alias)| {
+            let ident = &variant.ident;
+            let names = names
+                .iter()
+                .map(|name| {
+                    if bytes {
+                        LitByteStr::new(name.as_bytes(), ident.span()).into_token_stream()
+                    } else {
+                        name.into_token_stream()
+                    }
+                })
+                .collect::<Vec<_>>();
+            let result = result(variant, *deprecated_alias);
+            if variant.ascii_case_insensitive {
+                let ty = if bytes {
+                    quote!([::core::primitive::u8])
+                } else {
+                    quote!(::core::primitive::str)
+                };
+                quote! {
+                    __v if #(<#ty>::eq_ignore_ascii_case(__v, #names))||* => #result,
+                }
+            } else {
+                quote! {
+                    #(#names)|* => #result,
+                }
+            }
+        })
+        .collect();
+    (value, arms)
+}
@@ -0,0 +1,113 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::{bound::with_bound, input::Input};
+
+/// With `#[serde(str_interop)]`, compares and converts the enum as its serialized string.
+///
+/// `PartialEq<str>` and `From<Self> for String` go through `Display` for the `#[serde(pattern)]`
+/// variants and a payload that is not string-like. `AsRef<str>` is only emitted when every variant
+/// has a `&str` to hand out. There is no `Borrow<str>`, the derived `Hash` and `Eq` would not agree
+/// with it.
+pub fn impl_str_interop(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let private = quote!(#enum_str_expr::__private);
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let unit_variants = input.unit_variant_ser_names();
+    let str_payload_variant = input.str_payload_variant();
+    let has_payload = input.default_variant_type().is_some() || !input.pattern_variants.is_empty();
+
+    let display_generics = with_bound(
+        &input.generics,
+        input
+            .default_variant
+            .iter()
+            .filter(|default_variant| default_variant.serialize_with.is_none())
+            .filter_map(|default_variant| default_variant.r#type.as_ref())
+            .chain(
+                input
+                    .pattern_variants
+                    .iter()
+                    .map(|pattern_variant| &pattern_variant.r#type),
+            ),
+        &quote!(::core::fmt::Display),
+    );
+    let (impl_generics, _, where_clause) = display_generics.split_for_impl();
+
+    //
+    let eq_arms = unit_variants
+        .iter()
+        .map(|(ident, name)| quote!(Self::#ident => other == #name,));
+    let eq_payload_arm = if has_payload {
+        quote!(_ => #private::display_eq(self, other),)
+    } else {
+        quote!()
+    };
+
+    let into_string_arms = unit_variants
+        .iter()
+        .map(|(ident, name)| quote!(#impl_ident::#ident => #private::String::from(#name),));
+    let into_string_str_payload_arm = str_payload_variant.map(|default_variant| {
+        let ident = &default_variant.ident;
+        quote!(#impl_ident::#ident(s) => ::core::convert::Into::into(s),)
+    });
+    let into_string_payload_arm = if has_payload {
+        quote!(_ => #private::ToString::to_string(&value),)
+    } else {
+        quote!()
+    };
+
+    let mut token = quote! {
+        impl #impl_generics ::core::cmp::PartialEq<::core::primitive::str> for #impl_ident #ty_generics #where_clause {
+            fn eq(&self, other: &::core::primitive::str) -> ::core::primitive::bool {
+                match *self {
+                    #(#eq_arms)*
+                    #eq_payload_arm
+                }
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq<&::core::primitive::str> for #impl_ident #ty_generics #where_clause {
+            fn eq(&self, other: &&::core::primitive::str) -> ::core::primitive::bool {
+                <Self as ::core::cmp::PartialEq<::core::primitive::str>>::eq(self, *other)
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#impl_ident #ty_generics> for #private::String #where_clause {
+            fn from(value: #impl_ident #ty_generics) -> Self {
+                match value {
+                    #(#into_string_arms)*
+                    #into_string_str_payload_arm
+                    #into_string_payload_arm
+                }
+            }
+        }
+    };
+
+    //
+    if input.has_static_names() {
+        let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+        let as_ref_arms = unit_variants
+            .iter()
+            .map(|(ident, name)| quote!(Self::#ident => #name,));
+        let as_ref_payload_arm = str_payload_variant.map(|default_variant| {
+            let ident = &default_variant.ident;
+            quote!(Self::#ident(ref s) => ::core::convert::AsRef::<::core::primitive::str>::as_ref(s),)
+        });
+
+        token.extend(quote! {
+            impl #impl_generics ::core::convert::AsRef<::core::primitive::str> for #impl_ident #ty_generics #where_clause {
+                fn as_ref(&self) -> &::core::primitive::str {
+                    match *self {
+                        #(#as_ref_arms)*
+                        #as_ref_payload_arm
+                    }
+                }
+            }
+        });
+    }
+
+    token
+}
@@ -0,0 +1,144 @@
+use alloc::{
+    format,
+    string::{String, ToString as _},
+    vec,
+    vec::Vec,
+};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use super::input::{Input, JsonSchema};
+
+/// `schemars::JsonSchema` describing the serialized strings.
+///
+/// A closed enum is `{"type": "string", "enum": [...]}`, an enum with `#[serde(pattern)]` or
+/// `#[serde(other)]` variants is `{"type": "string", "examples": [...]}`. With doc comments on the
+/// variants, every value gets its own subschema with a `description`, under `oneOf` for a closed
+/// enum and `anyOf` for an open one.
+pub fn impl_json_schema(input: &Input, json_schema: &JsonSchema) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let schemars = quote!(#enum_str_expr::__private::schemars);
+    let impl_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let schema_name = input.ident.to_string();
+
+    let known = input
+        .variants
+        .iter()
+        .filter(|variant| variant.skip_serializing != Some(true))
+        .map(|variant| {
+            let name = input.variant_ser_name(variant);
+            let mut names = vec![name.clone()];
+            if json_schema.aliases && variant.skip_deserializing != Some(true) {
+                for alias in input.variant_de_names(variant) {
+                    if !names.contains(&alias) {
+                        names.push(alias);
+                    }
+                }
+            }
+            (names, json_schema.variant_description(&variant.ident))
+        })
+        .collect::<Vec<_>>();
+
+    let open = input
+        .pattern_variants
+        .iter()
+        .map(|pattern_variant| {
+            let pattern = (!pattern_variant.prefix.is_empty()
+                || !pattern_variant.suffix.is_empty())
+            .then(|| {
+                format!(
+                    "^{}.*{}$",
+                    escape_regex(&pattern_variant.prefix),
+                    escape_regex(&pattern_variant.suffix)
+                )
+            });
+            (
+                pattern,
+                json_schema.variant_description(&pattern_variant.ident),
+            )
+        })
+        .chain(input.default_variant.iter().map(|default_variant| {
+            (
+                None,
+                json_schema.variant_description(&default_variant.ident),
+            )
+        }))
+        .collect::<Vec<_>>();
+
+    let description = json_schema
+        .description
+        .as_ref()
+        .map(|description| quote!("description": #description,));
+
+    let has_variant_descriptions = known.iter().any(|(_, description)| description.is_some())
+        || open.iter().any(|(_, description)| description.is_some());
+
+    let schema = if has_variant_descriptions {
+        let subschemas = known
+            .iter()
+            .map(|(names, description)| {
+                let values = match &names[..] {
+                    [name] => quote!("const": #name),
+                    _ => quote!("enum": [#(#names),*]),
+                };
+                let description =
+                    description.map(|description| quote!(, "description": #description));
+                quote!({ "type": "string", #values #description })
+            })
+            .chain(open.iter().map(|(pattern, description)| {
+                let pattern = pattern
+                    .as_ref()
+                    .map(|pattern| quote!(, "pattern": #pattern));
+                let description =
+                    description.map(|description| quote!(, "description": #description));
+                quote!({ "type": "string" #pattern #description })
+            }))
+            .collect::<Vec<_>>();
+        let keyword = if open.is_empty() {
+            quote!("oneOf")
+        } else {
+            quote!("anyOf")
+        };
+        quote!({ #description #keyword: [#(#subschemas),*] })
+    } else {
+        let names = known
+            .iter()
+            .flat_map(|(names, _)| names)
+            .collect::<Vec<_>>();
+        let keyword = if open.is_empty() {
+            quote!("enum")
+        } else {
+            quote!("examples")
+        };
+        quote!({ #description "type": "string", #keyword: [#(#names),*] })
+    };
+
+    quote! {
+        impl #impl_generics #schemars::JsonSchema for #impl_ident #ty_generics #where_clause {
+            fn schema_name() -> #enum_str_expr::__private::Cow<'static, ::core::primitive::str> {
+                #enum_str_expr::__private::Cow::Borrowed(#schema_name)
+            }
+
+            fn schema_id() -> #enum_str_expr::__private::Cow<'static, ::core::primitive::str> {
+                #enum_str_expr::__private::Cow::Borrowed(::core::concat!(::core::module_path!(), "::", #schema_name))
+            }
+
+            fn json_schema(_generator: &mut #schemars::SchemaGenerator) -> #schemars::Schema {
+                #schemars::json_schema!(#schema)
+            }
+        }
+    }
+}
+
+fn escape_regex(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if "\\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,528 @@
+use alloc::{borrow::ToOwned as _, format, vec::Vec};
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, TokenStreamExt as _, format_ident, quote};
+
+use super::{
+    bound::{is_generic, with_bound, with_where_predicates},
+    discriminant::Tags,
+    input::Input,
+};
+
+//
+pub struct InputWrapper(pub Input);
+
+impl ToTokens for InputWrapper {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let input = &self.0;
+        let serde_expr = &input.serde_expr;
+        let enum_str_expr = &input.enum_str_expr;
+
+        //
+        let impl_vis = &input.vis;
+        let impl_ident = &input.ident;
+        let target = input.target();
+        let target_name = input.target_name();
+        // Like serde's remote derive, inherent functions taking the remote enum by reference.
+        let value = if input.remote.is_some() {
+            quote!(value)
+        } else {
+            quote!(self)
+        };
+        // Without `#[serde(serialize_with)]`, the payload is also written with `Display`.
+        let default_variant_types = input
+            .default_variant
+            .iter()
+            .filter(|default_variant| default_variant.serialize_with.is_none())
+            .filter_map(|default_variant| default_variant.r#type.as_ref());
+        let has_default_variant_type = input.default_variant_type().is_some();
+        let pattern_variant_types = input
+            .pattern_variants
+            .iter()
+            .map(|pattern_variant| &pattern_variant.r#type);
+        // Some variants have no name of their own.
+        let has_payload = has_default_variant_type || !input.pattern_variants.is_empty();
+
+        //
+        let impl_serialize_variants = &input
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                if variant.skip_serializing == Some(true) {
+                    let msg = format!("the enum variant {target_name}::{ident} cannot be serialized");
+                    quote! {
+                        #target::#ident => ::core::result::Result::Err(#serde_expr::ser::Error::custom(#msg)),
+                    }
+                } else if input.char {
+                    let c = input.variant_ser_char(variant);
+                    quote! {
+                        #target::#ident => #serde_expr::Serializer::serialize_char(serializer, #c),
+                    }
+                } else {
+                    let name = input.variant_ser_name(variant);
+                    quote! {
+                        #target::#ident => #serde_expr::Serializer::serialize_str(serializer, #name),
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        let impl_serialize_pattern_variants = input
+            .pattern_variants
+            .iter()
+            .map(|pattern_variant| {
+                let ident = &pattern_variant.ident;
+                let prefix = &pattern_variant.prefix;
+                let suffix = &pattern_variant.suffix;
+                quote! {
+                    #target::#ident(ref v) => #serde_expr::Serializer::collect_str(
+                        serializer,
+                        &::core::format_args!("{}{}{}", #prefix, v, #suffix),
+                    ),
+                }
+            })
+            .collect::<Vec<_>>();
+        let impl_serialize_default_variant = if let Some(default_variant) = &input.default_variant {
+            let ident = &default_variant.ident;
+            if default_variant.r#type.is_some() {
+                let serialize = match &default_variant.serialize_with {
+                    Some(serialize_with) => quote!(#serialize_with),
+                    None => quote!(#serde_expr::Serialize::serialize),
+                };
+                quote! {
+                    #target::#ident(ref s) => #serialize(s, serializer),
+                }
+            } else if input.char {
+                let c = input.default_variant_ser_char(default_variant);
+                quote! {
+                    #target::#ident => #serde_expr::Serializer::serialize_char(serializer, #c),
+                }
+            } else {
+                let name = input.default_variant_ser_name(default_variant);
+                quote! {
+                    #target::#ident => #serde_expr::Serializer::serialize_str(serializer, #name),
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        let serialize_generics = match input
+            .bound
+            .as_ref()
+            .and_then(|bound| bound.ser_predicates())
+        {
+            Some(predicates) => with_where_predicates(&input.generics, predicates),
+            None => with_bound(
+                &with_bound(
+                    &input.generics,
+                    default_variant_types.clone(),
+                    &quote!(#serde_expr::Serialize),
+                ),
+                pattern_variant_types.clone(),
+                &quote!(::core::fmt::Display),
+            ),
+        };
+        let (impl_generics, ty_generics, where_clause) = serialize_generics.split_for_impl();
+
+        // The discriminant instead of the name, for formats that are not human readable.
+        let serialize_compact = if let Some(repr) = &input.compact_repr {
+            let tags = Tags::new(input, repr);
+            let consts = &tags.consts;
+            let serialize_repr = format_ident!("serialize_{}", repr);
+            let variants = input
+                .variants
+                .iter()
+                .zip(&tags.variant_tags)
+                .map(|(variant, tag)| {
+                    let ident = &variant.ident;
+                    if variant.skip_serializing == Some(true) {
+                        let msg =
+                            format!("the enum variant {target_name}::{ident} cannot be serialized");
+                        quote! {
+                            #target::#ident => ::core::result::Result::Err(#serde_expr::ser::Error::custom(#msg)),
+                        }
+                    } else {
+                        quote! {
+                            #target::#ident => #serde_expr::Serializer::#serialize_repr(serializer, #tag),
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            let default_variant = match (&input.default_variant, &tags.default_variant_tag) {
+                (Some(default_variant), Some(tag)) => {
+                    let ident = &default_variant.ident;
+                    if default_variant.r#type.is_some() {
+                        quote! {
+                            #target::#ident(ref s) => {
+                                let mut tuple = #serde_expr::Serializer::serialize_tuple(serializer, 2)?;
+                                #serde_expr::ser::SerializeTuple::serialize_element(&mut tuple, &#tag)?;
+                                #serde_expr::ser::SerializeTuple::serialize_element(&mut tuple, s)?;
+                                #serde_expr::ser::SerializeTuple::end(tuple)
+                            }
+                        }
+                    } else {
+                        quote! {
+                            #target::#ident => #serde_expr::Serializer::#serialize_repr(serializer, #tag),
+                        }
+                    }
+                }
+                _ => quote!(),
+            };
+            quote! {
+                if !#serde_expr::Serializer::is_human_readable(&serializer) {
+                    #consts
+
+                    return match *#value {
+                        #(#variants)*
+                        #default_variant
+                    };
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        let serialize_body = quote! {
+            #serialize_compact
+
+            match *#value {
+                #(#impl_serialize_variants)*
+                #(#impl_serialize_pattern_variants)*
+                #impl_serialize_default_variant
+            }
+        };
+
+        let token = if input.remote.is_some() {
+            quote! {
+                impl #impl_generics #impl_ident #ty_generics #where_clause {
+                    #impl_vis fn serialize<__S>(value: &#target #ty_generics, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                    where
+                        __S: #serde_expr::Serializer,
+                    {
+                        #serialize_body
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics #serde_expr::Serialize for #impl_ident #ty_generics #where_clause {
+                    fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                    where
+                        __S: #serde_expr::Serializer,
+                    {
+                        #serialize_body
+                    }
+                }
+            }
+        };
+        tokens.append_all(token);
+
+        //
+        if input.has_explicit_discriminant() && !has_payload && input.remote.is_none() {
+            let tags = Tags::new(input, &format_ident!("i64"));
+            let consts = &tags.consts;
+            let arms = input
+                .variants
+                .iter()
+                .map(|variant| &variant.ident)
+                .chain(
+                    input
+                        .default_variant
+                        .iter()
+                        .map(|default_variant| &default_variant.ident),
+                )
+                .zip(tags.variant_tags.iter().chain(&tags.default_variant_tag))
+                .map(|(ident, tag)| quote!(#target::#ident => #tag,));
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+            let token = quote! {
+                impl #impl_generics ::core::convert::From<#impl_ident #ty_generics> for ::core::primitive::i64 #where_clause {
+                    fn from(value: #impl_ident #ty_generics) -> Self {
+                        #consts
+
+                        match value {
+                            #(#arms)*
+                        }
+                    }
+                }
+            };
+            tokens.append_all(token);
+        }
+
+        //
+        if input.char && !has_payload && input.remote.is_none() {
+            let arms = input
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let c = input.variant_ser_char(variant);
+                    quote!(#target::#ident => #c,)
+                })
+                .chain(input.default_variant.iter().map(|default_variant| {
+                    let ident = &default_variant.ident;
+                    let c = input.default_variant_ser_char(default_variant);
+                    quote!(#target::#ident => #c,)
+                }));
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+            let token = quote! {
+                impl #impl_generics ::core::convert::From<#impl_ident #ty_generics> for ::core::primitive::char #where_clause {
+                    fn from(value: #impl_ident #ty_generics) -> Self {
+                        match value {
+                            #(#arms)*
+                        }
+                    }
+                }
+            };
+            tokens.append_all(token);
+        }
+
+        //
+        let impl_display_variants = &input
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                let name = input.variant_display_name(variant);
+                quote! {
+                    #target::#ident => ::core::fmt::Display::fmt(#name, f),
+                }
+            })
+            .collect::<Vec<_>>();
+        let impl_display_pattern_variants = input
+            .pattern_variants
+            .iter()
+            .map(|pattern_variant| {
+                let ident = &pattern_variant.ident;
+                let prefix = &pattern_variant.prefix;
+                let suffix = &pattern_variant.suffix;
+                quote! {
+                    #target::#ident(ref v) => {
+                        f.write_str(#prefix)?;
+                        ::core::fmt::Display::fmt(v, f)?;
+                        f.write_str(#suffix)
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        let impl_display_default_variant = if let Some(default_variant) = &input.default_variant {
+            let ident = &default_variant.ident;
+            if let Some(serialize_with) = &default_variant.serialize_with {
+                // `&mut Formatter` is a `Serializer` of strings and primitives.
+                quote! {
+                    #target::#ident(ref s) => #serialize_with(s, f),
+                }
+            } else if default_variant.r#type.is_some() {
+                quote! {
+                    #target::#ident(ref s) => ::core::fmt::Display::fmt(s, f),
+                }
+            } else {
+                let name = input.default_variant_display_name(default_variant);
+                quote! {
+                    #target::#ident => ::core::fmt::Display::fmt(#name, f),
+                }
+            }
+        } else {
+            quote!()
+        };
+
+        let display_generics = with_bound(
+            &input.generics,
+            default_variant_types.chain(pattern_variant_types),
+            &quote!(::core::fmt::Display),
+        );
+        let (impl_generics, _, where_clause) = display_generics.split_for_impl();
+
+        let display_body = quote! {
+            match *#value {
+                #(#impl_display_variants)*
+                #(#impl_display_pattern_variants)*
+                #impl_display_default_variant
+            }
+        };
+
+        let token = if input.remote.is_some() {
+            quote! {
+                impl #impl_generics #impl_ident #ty_generics #where_clause {
+                    /// Like `Display::fmt`.
+                    #impl_vis fn fmt(value: &#target #ty_generics, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        #display_body
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics ::core::fmt::Display for #impl_ident #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        #display_body
+                    }
+                }
+            }
+        };
+        tokens.append_all(token);
+
+        //
+        let mut all_variants = input
+            .variants
+            .iter()
+            .filter(|variant| variant.skip_serializing != Some(true))
+            .map(|variant| (&variant.ident, input.variant_ser_name(variant)))
+            .collect::<Vec<_>>();
+        if let Some(default_variant) = &input.default_variant
+            && default_variant.r#type.is_none()
+        {
+            all_variants.push((
+                &default_variant.ident,
+                input.default_variant_ser_name(default_variant),
+            ));
+        }
+        let all_idents = all_variants.iter().map(|(ident, _)| ident);
+        let all_names = all_variants.iter().map(|(_, name)| name);
+
+        let as_str_variants = input
+            .variants
+            .iter()
+            .map(|variant| (&variant.ident, input.variant_ser_name(variant)))
+            .chain(
+                input
+                    .default_variant
+                    .iter()
+                    .filter(|default_variant| default_variant.r#type.is_none())
+                    .map(|default_variant| {
+                        (
+                            &default_variant.ident,
+                            input.default_variant_ser_name(default_variant),
+                        )
+                    }),
+            )
+            .map(|(ident, name)| {
+                if has_payload {
+                    quote!(#target::#ident => ::core::option::Option::Some(#name),)
+                } else {
+                    quote!(#target::#ident => #name,)
+                }
+            })
+            .collect::<Vec<_>>();
+        let as_str_payload_variants = input
+            .pattern_variants
+            .iter()
+            .map(|pattern_variant| &pattern_variant.ident)
+            .chain(
+                input
+                    .default_variant
+                    .iter()
+                    .filter(|default_variant| default_variant.r#type.is_some())
+                    .map(|default_variant| &default_variant.ident),
+            )
+            .map(|ident| quote!(#target::#ident(_) => ::core::option::Option::None,))
+            .collect::<Vec<_>>();
+        let (as_str_ty, trait_as_str) = if has_payload {
+            (
+                quote!(::core::option::Option<&'static ::core::primitive::str>),
+                quote!(Self::as_str(self)),
+            )
+        } else {
+            (
+                quote!(&'static ::core::primitive::str),
+                quote!(::core::option::Option::Some(Self::as_str(self))),
+            )
+        };
+        let as_str_doc = if has_payload {
+            "The serialized name, `None` for the `#[serde(pattern)]` and `#[serde(other)]` variants."
+        } else {
+            "The serialized name."
+        };
+
+        let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+        // `&'static [Self]` is only well-formed for `Self: 'static`.
+        let all_generics = if is_generic(&input.generics) {
+            with_where_predicates(&input.generics, &[syn::parse_quote!(Self: 'static)])
+        } else {
+            input.generics.to_owned()
+        };
+        let (all_impl_generics, _, all_where_clause) = all_generics.split_for_impl();
+
+        let as_str_body = quote! {
+            match *#value {
+                #(#as_str_variants)*
+                #(#as_str_payload_variants)*
+            }
+        };
+
+        if input.str_interop {
+            tokens.append_all(super::output_interop::impl_str_interop(input));
+        }
+
+        #[cfg(feature = "schemars")]
+        if let Some(json_schema) = &input.json_schema {
+            tokens.append_all(super::output_schemars::impl_json_schema(input, json_schema));
+        }
+
+        #[cfg(feature = "sqlx")]
+        if input.sqlx {
+            tokens.append_all(super::output_sql::impl_sqlx_encode(input));
+        }
+
+        #[cfg(feature = "diesel")]
+        if input.diesel {
+            tokens.append_all(super::output_sql::impl_diesel_to_sql(input));
+        }
+
+        #[cfg(feature = "postgres-types")]
+        if input.postgres_types {
+            tokens.append_all(super::output_sql::impl_postgres_to_sql(input));
+        }
+
+        if input.remote.is_some() {
+            let token = quote! {
+                impl #impl_generics #impl_ident #ty_generics #where_clause {
+                    /// The serialized names of the unit variants that can be serialized.
+                    #impl_vis const VARIANTS: &'static [&'static ::core::primitive::str] = &[#(#all_names),*];
+
+                    #[doc = #as_str_doc]
+                    #impl_vis const fn as_str(value: &#target #ty_generics) -> #as_str_ty {
+                        #as_str_body
+                    }
+                }
+            };
+            tokens.append_all(token);
+
+            return;
+        }
+
+        let token = quote! {
+            impl #impl_generics #impl_ident #ty_generics #where_clause {
+                /// The serialized names of `ALL`, in the same order.
+                #impl_vis const VARIANTS: &'static [&'static ::core::primitive::str] = &[#(#all_names),*];
+
+                #[doc = #as_str_doc]
+                #impl_vis const fn as_str(&self) -> #as_str_ty {
+                    #as_str_body
+                }
+            }
+
+            impl #all_impl_generics #impl_ident #ty_generics #all_where_clause {
+                /// The unit variants that can be serialized, in declaration order.
+                #impl_vis const ALL: &'static [Self] = &[#(Self::#all_idents),*];
+            }
+
+            impl #impl_generics #enum_str_expr::EnumStr for #impl_ident #ty_generics #where_clause {
+                const VARIANTS: &'static [&'static ::core::primitive::str] = Self::VARIANTS;
+
+                fn all() -> &'static [Self]
+                where
+                    Self: 'static,
+                {
+                    Self::ALL
+                }
+
+                fn as_str(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                    #trait_as_str
+                }
+            }
+        };
+        tokens.append_all(token);
+    }
+}
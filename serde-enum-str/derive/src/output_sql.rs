@@ -0,0 +1,289 @@
+#[cfg(feature = "diesel")]
+use alloc::vec::Vec;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{GenericParam, Generics, WherePredicate};
+
+use super::{
+    bound::{is_generic, with_where_predicates},
+    input::Input,
+};
+
+// The enums are stored as TEXT, written with the serialized names and read back with `FromStr`,
+// so `alias`, `ascii_case_insensitive` and the `#[serde(other)]` variant apply to the columns too.
+
+/// `sqlx::Type` and `sqlx::Encode`, for every database where `str` is a type.
+#[cfg(feature = "sqlx")]
+pub fn impl_sqlx_encode(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let sqlx = quote!(#enum_str_expr::__private::sqlx_core);
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let value = sql_value(input);
+
+    let type_generics = with_params(
+        &input.generics,
+        &[syn::parse_quote!(__DB)],
+        &[
+            syn::parse_quote!(__DB: #sqlx::database::Database),
+            syn::parse_quote!(::core::primitive::str: #sqlx::types::Type<__DB>),
+        ],
+    );
+    let (type_impl_generics, _, type_where_clause) = type_generics.split_for_impl();
+    let encode_generics = with_params(
+        &display_generics(input),
+        &[syn::parse_quote!('q), syn::parse_quote!(__DB)],
+        &[
+            syn::parse_quote!(__DB: #sqlx::database::Database),
+            syn::parse_quote!(::std::string::String: #sqlx::encode::Encode<'q, __DB>),
+        ],
+    );
+    let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+
+    quote! {
+        impl #type_impl_generics #sqlx::types::Type<__DB> for #impl_ident #ty_generics #type_where_clause {
+            fn type_info() -> <__DB as #sqlx::database::Database>::TypeInfo {
+                <::core::primitive::str as #sqlx::types::Type<__DB>>::type_info()
+            }
+
+            fn compatible(ty: &<__DB as #sqlx::database::Database>::TypeInfo) -> ::core::primitive::bool {
+                <::core::primitive::str as #sqlx::types::Type<__DB>>::compatible(ty)
+            }
+        }
+
+        impl #encode_impl_generics #sqlx::encode::Encode<'q, __DB> for #impl_ident #ty_generics #encode_where_clause {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <__DB as #sqlx::database::Database>::ArgumentBuffer<'q>,
+            ) -> ::core::result::Result<#sqlx::encode::IsNull, #sqlx::error::BoxDynError> {
+                let value = #value;
+                <::std::string::String as #sqlx::encode::Encode<'q, __DB>>::encode(value.into_owned(), buf)
+            }
+        }
+    }
+}
+
+/// `sqlx::Decode`, for every database where `&str` can be decoded.
+#[cfg(feature = "sqlx")]
+pub fn impl_sqlx_decode(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let sqlx = quote!(#enum_str_expr::__private::sqlx_core);
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let generics = with_params(
+        &from_str_generics(input),
+        &[syn::parse_quote!('r), syn::parse_quote!(__DB)],
+        &[
+            syn::parse_quote!(__DB: #sqlx::database::Database),
+            syn::parse_quote!(&'r ::core::primitive::str: #sqlx::decode::Decode<'r, __DB>),
+        ],
+    );
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #sqlx::decode::Decode<'r, __DB> for #impl_ident #ty_generics #where_clause {
+            fn decode(
+                value: <__DB as #sqlx::database::Database>::ValueRef<'r>,
+            ) -> ::core::result::Result<Self, #sqlx::error::BoxDynError> {
+                let value = <&'r ::core::primitive::str as #sqlx::decode::Decode<'r, __DB>>::decode(value)?;
+                ::core::str::FromStr::from_str(value).map_err(::core::convert::Into::into)
+            }
+        }
+    }
+}
+
+/// diesel's `ToSql<Text, _>`, which binds a `&str` borrowed from the value.
+#[cfg(feature = "diesel")]
+pub fn impl_diesel_to_sql(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let diesel = quote!(#enum_str_expr::__private::diesel);
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let arms = input
+        .unit_variant_ser_names()
+        .into_iter()
+        .map(|(ident, name)| quote!(Self::#ident => #name,))
+        .chain(input.str_payload_variant().map(|default_variant| {
+            let ident = &default_variant.ident;
+            quote!(Self::#ident(ref s) => ::core::convert::AsRef::<::core::primitive::str>::as_ref(s),)
+        }))
+        .collect::<Vec<_>>();
+
+    let generics = with_params(
+        &input.generics,
+        &[syn::parse_quote!(__DB)],
+        &[
+            syn::parse_quote!(__DB: #diesel::backend::Backend),
+            syn::parse_quote!(::core::primitive::str: #diesel::serialize::ToSql<#diesel::sql_types::Text, __DB>),
+        ],
+    );
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #diesel::serialize::ToSql<#diesel::sql_types::Text, __DB> for #impl_ident #ty_generics #where_clause {
+            fn to_sql<'__b>(&'__b self, out: &mut #diesel::serialize::Output<'__b, '_, __DB>) -> #diesel::serialize::Result {
+                let value: &'__b ::core::primitive::str = match *self {
+                    #(#arms)*
+                };
+                <::core::primitive::str as #diesel::serialize::ToSql<#diesel::sql_types::Text, __DB>>::to_sql(value, out)
+            }
+        }
+    }
+}
+
+/// diesel's `FromSql<Text, _>`.
+#[cfg(feature = "diesel")]
+pub fn impl_diesel_from_sql(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let diesel = quote!(#enum_str_expr::__private::diesel);
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let generics = with_params(
+        &from_str_generics(input),
+        &[syn::parse_quote!(__DB)],
+        &[
+            syn::parse_quote!(__DB: #diesel::backend::Backend),
+            syn::parse_quote!(::std::string::String: #diesel::deserialize::FromSql<#diesel::sql_types::Text, __DB>),
+        ],
+    );
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #diesel::deserialize::FromSql<#diesel::sql_types::Text, __DB> for #impl_ident #ty_generics #where_clause {
+            fn from_sql(bytes: <__DB as #diesel::backend::Backend>::RawValue<'_>) -> #diesel::deserialize::Result<Self> {
+                let value = <::std::string::String as #diesel::deserialize::FromSql<#diesel::sql_types::Text, __DB>>::from_sql(bytes)?;
+                ::core::str::FromStr::from_str(&value).map_err(::core::convert::Into::into)
+            }
+        }
+    }
+}
+
+/// `postgres_types::ToSql`, for the types `&str` accepts.
+#[cfg(feature = "postgres-types")]
+pub fn impl_postgres_to_sql(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let postgres_types = quote!(#enum_str_expr::__private::postgres_types);
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let value = sql_value(input);
+
+    let generics = display_generics(input);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #postgres_types::ToSql for #impl_ident #ty_generics #where_clause {
+            fn to_sql(
+                &self,
+                ty: &#postgres_types::Type,
+                out: &mut #postgres_types::private::BytesMut,
+            ) -> ::core::result::Result<#postgres_types::IsNull, ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>> {
+                let value = #value;
+                <&::core::primitive::str as #postgres_types::ToSql>::to_sql(&&*value, ty, out)
+            }
+
+            fn accepts(ty: &#postgres_types::Type) -> ::core::primitive::bool {
+                <&::core::primitive::str as #postgres_types::ToSql>::accepts(ty)
+            }
+
+            #postgres_types::to_sql_checked!();
+        }
+    }
+}
+
+/// `postgres_types::FromSql`, for the types `&str` accepts.
+#[cfg(feature = "postgres-types")]
+pub fn impl_postgres_from_sql(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let postgres_types = quote!(#enum_str_expr::__private::postgres_types);
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let generics = with_params(&from_str_generics(input), &[syn::parse_quote!('a)], &[]);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #postgres_types::FromSql<'a> for #impl_ident #ty_generics #where_clause {
+            fn from_sql(
+                ty: &#postgres_types::Type,
+                raw: &'a [::core::primitive::u8],
+            ) -> ::core::result::Result<Self, ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Sync + ::core::marker::Send>> {
+                let value = <&::core::primitive::str as #postgres_types::FromSql<'_>>::from_sql(ty, raw)?;
+                ::core::str::FromStr::from_str(value).map_err(::core::convert::Into::into)
+            }
+
+            fn accepts(ty: &#postgres_types::Type) -> ::core::primitive::bool {
+                <&::core::primitive::str as #postgres_types::FromSql<'_>>::accepts(ty)
+            }
+        }
+    }
+}
+
+/// The serialized name as a `Cow<str>`, borrowed for the unit variants and formatted otherwise.
+#[cfg(any(feature = "sqlx", feature = "postgres-types"))]
+fn sql_value(input: &Input) -> TokenStream {
+    let enum_str_expr = &input.enum_str_expr;
+    let private = quote!(#enum_str_expr::__private);
+    let arms = input
+        .unit_variant_ser_names()
+        .into_iter()
+        .map(|(ident, name)| quote!(Self::#ident => #private::Cow::Borrowed(#name),));
+    let payload_arm =
+        if input.default_variant_type().is_some() || !input.pattern_variants.is_empty() {
+            quote!(_ => #private::Cow::Owned(#private::ToString::to_string(self)),)
+        } else {
+            quote!()
+        };
+
+    quote! {
+        match *self {
+            #(#arms)*
+            #payload_arm
+        }
+    }
+}
+
+/// `Self: Display` for the payloads of a generic enum.
+#[cfg(any(feature = "sqlx", feature = "postgres-types"))]
+fn display_generics(input: &Input) -> Generics {
+    if is_generic(&input.generics) {
+        with_where_predicates(
+            &input.generics,
+            &[syn::parse_quote!(Self: ::core::fmt::Display)],
+        )
+    } else {
+        input.generics.to_owned()
+    }
+}
+
+/// `Self: FromStr` for the payloads of a generic enum.
+fn from_str_generics(input: &Input) -> Generics {
+    let enum_str_expr = &input.enum_str_expr;
+    if is_generic(&input.generics) {
+        with_where_predicates(
+            &input.generics,
+            &[syn::parse_quote!(Self: ::core::str::FromStr<Err = #enum_str_expr::ParseEnumError>)],
+        )
+    } else {
+        input.generics.to_owned()
+    }
+}
+
+/// The lifetimes first, then the type parameters.
+fn with_params(
+    generics: &Generics,
+    params: &[GenericParam],
+    predicates: &[WherePredicate],
+) -> Generics {
+    let mut generics = with_where_predicates(generics, predicates);
+    for param in params {
+        match param {
+            GenericParam::Lifetime(_) => generics.params.insert(0, param.to_owned()),
+            _ => generics.params.push(param.to_owned()),
+        }
+    }
+    generics
+}
@@ -0,0 +1,178 @@
+use alloc::{borrow::ToOwned as _, format, string::String};
+
+use darling::{
+    Error as DarlingError, FromDeriveInput, Result as DarlingResult,
+    ast::Data,
+    util::{Ignored, SpannedValue},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    DeriveInput, Error as SynError, Expr, Generics, Ident,
+    parse::{Parse, ParseStream},
+};
+
+use super::bound::{check_lifetime_de, is_generic, with_lifetime_de, with_where_predicates};
+
+/// A struct written as its `Display` string and read back with `FromStr`.
+pub struct StructInput {
+    pub ident: Ident,
+    pub generics: Generics,
+    /// `#[serde(expecting = "...")]`, for the `invalid type` errors.
+    pub expecting: String,
+    pub serde_expr: Expr,
+}
+
+impl Parse for StructInput {
+    fn parse(input: ParseStream) -> Result<Self, SynError> {
+        let derive_input = DeriveInput::parse(input)?;
+        Self::from_derive_input(&derive_input).map_err(Into::into)
+    }
+}
+
+impl StructInput {
+    fn from_derive_input(derive_input: &DeriveInput) -> DarlingResult<Self> {
+        let struct_derive_input = StructDeriveInput::from_derive_input(derive_input)?;
+        if !struct_derive_input.data.is_struct() {
+            return Err(DarlingError::custom(
+                "input must be a struct, use Serialize_enum_str and Deserialize_enum_str for enums",
+            )
+            .with_span(&derive_input.ident));
+        }
+
+        let mut errors = DarlingError::accumulator();
+
+        let ident = struct_derive_input.ident.to_owned();
+        let generics = struct_derive_input.generics.to_owned();
+        errors.handle(check_lifetime_de(&generics).map_err(DarlingError::from));
+        let expecting = struct_derive_input
+            .expecting
+            .as_ref()
+            .map(|expecting| (**expecting).to_owned())
+            .unwrap_or_else(|| format!("a string for {ident}"));
+        let serde_expr = struct_derive_input
+            .crate_
+            .as_ref()
+            .and_then(|crate_str| {
+                errors.handle(syn::parse_str::<Expr>(crate_str).map_err(|_| {
+                    DarlingError::from(SynError::new(
+                        crate_str.span(),
+                        r#"#[serde(crate = "...")] must be an Expr"#,
+                    ))
+                }))
+            })
+            .unwrap_or_else(|| syn::parse_str::<Expr>("serde").expect(""));
+
+        errors.finish_with(Self {
+            ident,
+            generics,
+            expecting,
+            serde_expr,
+        })
+    }
+}
+
+#[derive(FromDeriveInput, Debug)]
+#[darling(attributes(serde))]
+struct StructDeriveInput {
+    ident: Ident,
+    generics: Generics,
+    data: Data<Ignored, Ignored>,
+
+    #[darling(default, rename = "crate")]
+    crate_: Option<SpannedValue<String>>,
+    #[darling(default)]
+    expecting: Option<SpannedValue<String>>,
+}
+
+/// `Serialize` with `Serializer::collect_str`.
+pub fn impl_serialize(input: &StructInput) -> TokenStream {
+    let serde_expr = &input.serde_expr;
+    let impl_ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let generics = if is_generic(&input.generics) {
+        with_where_predicates(
+            &input.generics,
+            &[syn::parse_quote!(#impl_ident #ty_generics: ::core::fmt::Display)],
+        )
+    } else {
+        input.generics.to_owned()
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #serde_expr::Serialize for #impl_ident #ty_generics #where_clause {
+            fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+            where
+                __S: #serde_expr::Serializer,
+            {
+                #serde_expr::Serializer::collect_str(serializer, self)
+            }
+        }
+    }
+}
+
+/// `Deserialize` from a string or UTF-8 bytes, with the `FromStr` error as a custom error.
+pub fn impl_deserialize(input: &StructInput) -> TokenStream {
+    let serde_expr = &input.serde_expr;
+    let impl_ident = &input.ident;
+    let expecting = &input.expecting;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let generics = with_where_predicates(
+        &with_lifetime_de(&input.generics, &[]),
+        &[
+            syn::parse_quote!(#impl_ident #ty_generics: ::core::str::FromStr),
+            syn::parse_quote!(<#impl_ident #ty_generics as ::core::str::FromStr>::Err: ::core::fmt::Display),
+        ],
+    );
+    let (impl_generics, visitor_ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #serde_expr::Deserialize<'de> for #impl_ident #ty_generics #where_clause {
+            fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+            where
+                __D: #serde_expr::Deserializer<'de>,
+            {
+                struct __Visitor #impl_generics #where_clause {
+                    marker: ::core::marker::PhantomData<fn() -> #impl_ident #ty_generics>,
+                    lifetime: ::core::marker::PhantomData<&'de ()>,
+                }
+
+                impl #impl_generics #serde_expr::de::Visitor<'de> for __Visitor #visitor_ty_generics #where_clause {
+                    type Value = #impl_ident #ty_generics;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        f.write_str(#expecting)
+                    }
+
+                    fn visit_str<__E>(self, v: &::core::primitive::str) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: #serde_expr::de::Error,
+                    {
+                        ::core::str::FromStr::from_str(v).map_err(#serde_expr::de::Error::custom)
+                    }
+
+                    fn visit_bytes<__E>(self, v: &[::core::primitive::u8]) -> ::core::result::Result<Self::Value, __E>
+                    where
+                        __E: #serde_expr::de::Error,
+                    {
+                        match ::core::str::from_utf8(v) {
+                            ::core::result::Result::Ok(s) => self.visit_str(s),
+                            ::core::result::Result::Err(_) => ::core::result::Result::Err(
+                                #serde_expr::de::Error::invalid_value(#serde_expr::de::Unexpected::Bytes(v), &self),
+                            ),
+                        }
+                    }
+                }
+
+                #serde_expr::Deserializer::deserialize_str(
+                    deserializer,
+                    __Visitor {
+                        marker: ::core::marker::PhantomData,
+                        lifetime: ::core::marker::PhantomData,
+                    },
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use core::fmt::{self, Write as _};
+
+/// `value.to_string() == other`, without the allocation.
+pub fn display_eq(value: &dyn fmt::Display, other: &str) -> bool {
+    struct Eq<'a>(&'a str);
+
+    impl fmt::Write for Eq<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            match self.0.strip_prefix(s) {
+                Some(rest) => {
+                    self.0 = rest;
+                    Ok(())
+                }
+                None => Err(fmt::Error),
+            }
+        }
+    }
+
+    let mut eq = Eq(other);
+    write!(eq, "{value}").is_ok() && eq.0.is_empty()
+}
@@ -0,0 +1,15 @@
+/// Implemented by `Serialize_enum_str`, for code that works with any derived enum.
+///
+/// The derive also emits `as_str`, `VARIANTS` and `ALL` as inherent items, usable in `const` contexts.
+pub trait EnumStr: Sized {
+    /// The serialized names of `ALL`, in the same order.
+    const VARIANTS: &'static [&'static str];
+
+    /// The unit variants that can be serialized, in declaration order.
+    fn all() -> &'static [Self]
+    where
+        Self: 'static;
+
+    /// The serialized name, `None` for a `#[serde(other)]` variant with a payload.
+    fn as_str(&self) -> Option<&'static str>;
+}
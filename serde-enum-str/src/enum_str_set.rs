@@ -0,0 +1,332 @@
+use alloc::{
+    borrow::ToOwned as _,
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::{fmt, marker::PhantomData, slice, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+
+use crate::EnumStr;
+
+/// The separator of an [`EnumStrSet`].
+pub trait Delimiter {
+    /// Written between the members, and split on when parsing.
+    const SEPARATOR: char;
+    /// Trim whitespace around the members when parsing.
+    const TRIM: bool = true;
+}
+
+/// `"read,write"`, also parses `"read, write"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Comma;
+impl Delimiter for Comma {
+    const SEPARATOR: char = ',';
+}
+
+/// `"read write"`, also parses repeated spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Space;
+impl Delimiter for Space {
+    const SEPARATOR: char = ' ';
+}
+
+/// `"read;write"`, also parses `"read; write"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Semicolon;
+impl Delimiter for Semicolon {
+    const SEPARATOR: char = ';';
+}
+
+/// A set of serde-enum-str variants, (de)serialized as one delimited string like `"read,write"`.
+///
+/// The members are parsed with the enum's `FromStr`, so `rename`, `rename_all` and `alias` apply.
+/// Duplicates are dropped, empty members are skipped, and the members are kept in declaration
+/// order, with the `#[serde(other)]` and `#[serde(pattern)]` ones last in insertion order.
+pub struct EnumStrSet<T, D = Comma> {
+    members: Vec<T>,
+    delimiter: PhantomData<fn() -> D>,
+}
+
+impl<T, D> EnumStrSet<T, D> {
+    pub const fn new() -> Self {
+        Self {
+            members: Vec::new(),
+            delimiter: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.members.iter()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.members
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.members
+    }
+}
+
+impl<T, D> EnumStrSet<T, D>
+where
+    T: EnumStr + PartialEq + 'static,
+{
+    /// Returns `false` if the set already contained the member.
+    pub fn insert(&mut self, member: T) -> bool {
+        if self.members.contains(&member) {
+            return false;
+        }
+        let rank = Self::rank(&member);
+        let index = self
+            .members
+            .iter()
+            .position(|x| Self::rank(x) > rank)
+            .unwrap_or(self.members.len());
+        self.members.insert(index, member);
+        true
+    }
+
+    /// Returns `false` if the set did not contain the member.
+    pub fn remove(&mut self, member: &T) -> bool {
+        match self.members.iter().position(|x| x == member) {
+            Some(index) => {
+                self.members.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, member: &T) -> bool {
+        self.members.contains(member)
+    }
+
+    fn rank(member: &T) -> usize {
+        T::all()
+            .iter()
+            .position(|x| x == member)
+            .unwrap_or(usize::MAX)
+    }
+}
+
+impl<T, D> Default for EnumStrSet<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, D> Clone for EnumStrSet<T, D> {
+    fn clone(&self) -> Self {
+        Self {
+            members: self.members.clone(),
+            delimiter: PhantomData,
+        }
+    }
+}
+
+impl<T: PartialEq, D> PartialEq for EnumStrSet<T, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.members == other.members
+    }
+}
+
+impl<T: Eq, D> Eq for EnumStrSet<T, D> {}
+
+impl<T: fmt::Debug, D> fmt::Debug for EnumStrSet<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.members).finish()
+    }
+}
+
+impl<T, D> FromIterator<T> for EnumStrSet<T, D>
+where
+    T: EnumStr + PartialEq + 'static,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T, D> Extend<T> for EnumStrSet<T, D>
+where
+    T: EnumStr + PartialEq + 'static,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for member in iter {
+            self.insert(member);
+        }
+    }
+}
+
+impl<T, D> IntoIterator for EnumStrSet<T, D> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.into_iter()
+    }
+}
+
+impl<'a, T, D> IntoIterator for &'a EnumStrSet<T, D> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
+impl<T: EnumStr + fmt::Display, D: Delimiter> EnumStrSet<T, D> {
+    fn write_names<W: fmt::Write>(&self, w: &mut W) -> Result<(), NameError> {
+        for (i, member) in self.members.iter().enumerate() {
+            if i > 0 {
+                w.write_char(D::SEPARATOR)?;
+            }
+            match member.as_str() {
+                Some(name) if !T::VARIANTS.contains(&name) => {
+                    return Err(NameError::SkipSerializing(name));
+                }
+                Some(name) if name.contains(D::SEPARATOR) => {
+                    return Err(NameError::Separator(name.to_owned(), D::SEPARATOR));
+                }
+                Some(name) => w.write_str(name)?,
+                None => {
+                    let name = member.to_string();
+                    if name.contains(D::SEPARATOR) {
+                        return Err(NameError::Separator(name, D::SEPARATOR));
+                    }
+                    w.write_str(&name)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Why a set cannot be written so that `FromStr` parses it back.
+enum NameError {
+    Fmt,
+    SkipSerializing(&'static str),
+    Separator(String, char),
+}
+
+impl From<fmt::Error> for NameError {
+    fn from(_: fmt::Error) -> Self {
+        Self::Fmt
+    }
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fmt => f.write_str("a Display implementation returned an error"),
+            Self::SkipSerializing(name) => write!(f, "member `{name}` cannot be serialized"),
+            Self::Separator(name, separator) => {
+                write!(f, "member `{name}` contains the separator `{separator}`")
+            }
+        }
+    }
+}
+
+/// Writes the serialized names, not the `#[serde(display)]` ones, so that `FromStr` parses it back.
+///
+/// Fails for a `#[serde(skip_serializing)]` member and for a name that contains the separator.
+impl<T: EnumStr + fmt::Display, D: Delimiter> fmt::Display for EnumStrSet<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_names(f).map_err(|_| fmt::Error)
+    }
+}
+
+/// Fails with the error of the first unknown member.
+impl<T, D> FromStr for EnumStrSet<T, D>
+where
+    T: FromStr + EnumStr + PartialEq + 'static,
+    D: Delimiter,
+{
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::new();
+        for member in s.split(D::SEPARATOR) {
+            let member = if D::TRIM { member.trim() } else { member };
+            if member.is_empty() {
+                continue;
+            }
+            set.insert(member.parse()?);
+        }
+        Ok(set)
+    }
+}
+
+impl<T: EnumStr + fmt::Display, D: Delimiter> Serialize for EnumStrSet<T, D> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut names = String::new();
+        self.write_names(&mut names).map_err(ser::Error::custom)?;
+        serializer.serialize_str(&names)
+    }
+}
+
+impl<'de, T, D> Deserialize<'de> for EnumStrSet<T, D>
+where
+    T: FromStr + EnumStr + PartialEq + 'static,
+    T::Err: fmt::Display,
+    D: Delimiter,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        struct Visitor<T, D>(PhantomData<fn() -> (T, D)>);
+
+        impl<T, D> de::Visitor<'_> for Visitor<T, D>
+        where
+            T: FromStr + EnumStr + PartialEq + 'static,
+            T::Err: fmt::Display,
+            D: Delimiter,
+        {
+            type Value = EnumStrSet<T, D>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    formatter,
+                    "a string of names separated by `{}`",
+                    D::SEPARATOR
+                )
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                v.parse().map_err(|err: T::Err| E::custom(err))
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match core::str::from_utf8(v) {
+                    Ok(s) => self.visit_str(s),
+                    Err(_) => Err(E::invalid_value(de::Unexpected::Bytes(v), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(Visitor(PhantomData))
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! ```Cargo.toml
 //! [dependencies]
-//! serde-enum-str = "0.3"
+//! serde-enum-str = "0.6"
 //! serde = { version = "1", features = ["derive"] }
 //! ```
 //!
@@ -31,36 +31,167 @@
 //!     assert_eq!(Foo::A.to_string(), "a");
 //!     assert_eq!("a".parse::<Foo>().unwrap(), Foo::A);
 //!
+//!     assert_eq!(Foo::A.as_str(), Some("a"));
+//!     assert_eq!(Foo::VARIANTS, &["a", "B"]);
+//!     assert_eq!(Foo::ALL, &[Foo::A, Foo::B]);
+//!
 //!     Ok(())
 //! }
 //! ```
-#![allow(clippy::single_match_else)]
+//!
+//! `FromStr`, `TryFrom<&str>` and `TryFrom<String>` fail with a [`ParseEnumError`], which keeps the
+//! rejected input, the enum name and the accepted names, and displays as
+//! ``unknown Foo `x`; expected one of a, B``.
+//!
+//! Newtypes can be (de)serialized through their `Display` and `FromStr` impls.
+//!
+//! ```rust,ignore
+//! use serde_enum_str::{Deserialize_struct_str, Serialize_struct_str};
+//!
+//! #[derive(Deserialize_struct_str, Serialize_struct_str)]
+//! #[serde(expecting = "an account id")]
+//! struct AccountId(u64);
+//!
+//! impl core::fmt::Display for AccountId { /* ... */ }
+//! impl core::str::FromStr for AccountId { /* ... */ }
+//! ```
+//!
+//! ## clap
+//!
+//! With the `clap` feature, `#[serde(value_enum)]` implements `clap::ValueEnum` from the same names.
+//! The serialized names are the possible values, the `alias` names are clap aliases, and the
+//! `skip_deserializing` variants are left out.
+//!
+//! A `#[serde(other)]` variant, with or without a payload, is never listed as a possible value.
+//! `ValueEnum::from_str` falls back to the enum's `FromStr` and returns it for any unknown value,
+//! but clap's default `EnumValueParser` only accepts the listed names. To accept the other values
+//! on the command line, parse the argument with `FromStr` instead. `ValueEnum` is only implemented
+//! with `#[serde(value_enum)]`; the `clap` feature alone adds nothing to an enum.
+//!
+//! ```rust,ignore
+//! #[derive(Deserialize_enum_str, Serialize_enum_str, Clone)]
+//! #[serde(rename_all = "lowercase", value_enum)]
+//! enum Codec {
+//!     Gzip,
+//!     Br,
+//!     #[serde(other)]
+//!     Other(String),
+//! }
+//!
+//! #[derive(clap::Parser)]
+//! struct Cli {
+//!     // `--codec zstd` is an error
+//!     #[arg(long, value_enum)]
+//!     codec: Codec,
+//!     // `--fallback zstd` is `Codec::Other("zstd")`
+//!     #[arg(long, value_parser = <Codec as core::str::FromStr>::from_str)]
+//!     fallback: Codec,
+//! }
+//! ```
+//!
+//! ## schemars
+//!
+//! With the `schemars` feature, `#[serde(json_schema)]` implements `schemars::JsonSchema` for the
+//! serialized strings. A closed enum is a string `enum` of the serialized names, without the
+//! `skip_serializing` variants. An enum with `#[serde(pattern)]` or `#[serde(other)]` variants
+//! accepts any string, so the names are only listed as `examples`. The doc comments on the variants
+//! become `description`s, and `#[serde(json_schema(aliases))]` also lists the `alias` names.
+//!
+//! ```rust,ignore
+//! #[derive(Deserialize_enum_str, Serialize_enum_str)]
+//! #[serde(rename_all = "kebab-case", json_schema)]
+//! enum Format {
+//!     Yaml,
+//!     PrettyJson,
+//! }
+//!
+//! // {"type": "string", "enum": ["yaml", "pretty-json"], ...}
+//! let schema = schemars::schema_for!(Format);
+//! ```
+//!
+//! ## SQL
+//!
+//! The `sqlx`, `diesel` and `postgres-types` features store an enum in a TEXT column, opted into
+//! with `#[serde(sqlx)]`, `#[serde(diesel)]` and `#[serde(postgres_types)]`:
+//!
+//! * `sqlx`: `sqlx::Type`, `Encode` and `Decode`, for every database where `str` is a type.
+//! * `diesel`: `ToSql<Text, _>` and `FromSql<Text, _>`. Add
+//!   `#[derive(AsExpression, FromSqlRow)]` and `#[diesel(sql_type = Text)]` to use the enum as a
+//!   column type.
+//! * `postgres-types`: `postgres_types::ToSql` and `FromSql`, for the types `&str` accepts.
+//!
+//! The serialized names are written, and the values are read back with `FromStr`, so `alias`,
+//! `ascii_case_insensitive` and the `#[serde(other)]` variant apply to the column too. diesel binds
+//! a `&str` borrowed from the value, so `#[serde(diesel)]` cannot be used with `#[serde(pattern)]`
+//! variants or a `#[serde(other)]` payload that is not a string.
+//!
+//! ```rust,ignore
+//! #[derive(Deserialize_enum_str, Serialize_enum_str)]
+//! #[serde(rename_all = "snake_case", sqlx)]
+//! enum Status {
+//!     InProgress,
+//!     Done,
+//!     #[serde(other)]
+//!     Other(String),
+//! }
+//!
+//! sqlx::query("INSERT INTO tasks (status) VALUES (?)")
+//!     .bind(Status::InProgress)
+//!     .execute(&pool)
+//!     .await?;
+//! ```
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
-extern crate proc_macro;
 
-use proc_macro::TokenStream;
-use quote::quote;
-use syn::parse_macro_input;
+pub use serde_enum_str_derive::{
+    Deserialize_enum_str, Deserialize_struct_str, Serialize_enum_str, Serialize_struct_str,
+};
 
-mod input;
-mod output;
-mod output_de;
-mod output_ser;
+mod enum_str;
+pub use enum_str::EnumStr;
 
-#[proc_macro_derive(Serialize_enum_str, attributes(serde))]
-pub fn derive_serialize(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as self::input::Input);
-    let input = self::output_ser::InputWrapper(input);
+mod enum_str_set;
+pub use enum_str_set::{Comma, Delimiter, EnumStrSet, Semicolon, Space};
 
-    TokenStream::from(quote!(#input))
-}
+mod parse_enum_error;
+pub use parse_enum_error::ParseEnumError;
+
+mod try_from_discriminant_error;
+pub use try_from_discriminant_error::TryFromDiscriminantError;
+
+mod display_eq;
+
+mod parse_str_deserializer;
+
+#[cfg(feature = "clap")]
+mod value_enum;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::{String, ToString};
+
+    pub use crate::display_eq::display_eq;
+
+    pub use crate::parse_str_deserializer::ParseStrDeserializer;
+
+    #[cfg(feature = "clap")]
+    pub use clap;
+
+    #[cfg(feature = "clap")]
+    pub use crate::value_enum::value_enum_from_str;
+
+    pub use alloc::borrow::Cow;
+
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+
+    #[cfg(feature = "sqlx")]
+    pub use sqlx_core;
 
-#[proc_macro_derive(Deserialize_enum_str, attributes(serde))]
-pub fn derive_deserialize(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as self::input::Input);
-    let input = self::output_de::InputWrapper(input);
+    #[cfg(feature = "diesel")]
+    pub use diesel;
 
-    TokenStream::from(quote!(#input))
+    #[cfg(feature = "postgres-types")]
+    pub use postgres_types;
 }
@@ -0,0 +1,104 @@
+use alloc::string::{String, ToString as _};
+use core::fmt;
+
+/// The error of the derived `FromStr` and `TryFrom<&str>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    input: String,
+    enum_name: &'static str,
+    expected: &'static [&'static str],
+    reason: Option<String>,
+}
+
+impl ParseEnumError {
+    pub fn new(
+        input: impl Into<String>,
+        enum_name: &'static str,
+        expected: &'static [&'static str],
+    ) -> Self {
+        Self {
+            input: input.into(),
+            enum_name,
+            expected,
+            reason: None,
+        }
+    }
+
+    /// For the derived `TryFrom<&[u8]>` and `from_bytes`, with the input converted lossily.
+    pub fn from_bytes(
+        input: &[u8],
+        enum_name: &'static str,
+        expected: &'static [&'static str],
+    ) -> Self {
+        Self::new(String::from_utf8_lossy(input), enum_name, expected)
+    }
+
+    /// The input matched no variant name, and the `#[serde(other)]` payload rejected it.
+    pub fn with_reason(mut self, reason: impl fmt::Display) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+
+    /// The accepted names, without aliases.
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(reason) = &self.reason {
+            return write!(f, "invalid {} `{}`: {}", self.enum_name, self.input, reason);
+        }
+
+        write!(f, "unknown {} `{}`", self.enum_name, self.input)?;
+        if let Some((first, rest)) = self.expected.split_first() {
+            write!(f, "; expected one of {first}")?;
+            for name in rest {
+                write!(f, ", {name}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ParseEnumError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseEnumError::new("xx", "Region", &["us-east", "eu-west"]).to_string(),
+            "unknown Region `xx`; expected one of us-east, eu-west"
+        );
+        assert_eq!(
+            ParseEnumError::new("xx", "Region", &[]).to_string(),
+            "unknown Region `xx`"
+        );
+        assert_eq!(
+            ParseEnumError::new("xx", "Addr", &["a"])
+                .with_reason("invalid IPv4 address syntax")
+                .to_string(),
+            "invalid Addr `xx`: invalid IPv4 address syntax"
+        );
+        assert_eq!(
+            ParseEnumError::from_bytes(b"x\xff", "Region", &[]).to_string(),
+            "unknown Region `x\u{fffd}`"
+        );
+    }
+}
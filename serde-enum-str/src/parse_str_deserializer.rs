@@ -0,0 +1,98 @@
+use core::{marker::PhantomData, str::FromStr};
+
+use serde::{
+    Deserializer,
+    de::{self, IntoDeserializer as _, Visitor, value::StrDeserializer},
+    forward_to_deserialize_any,
+};
+
+/// The `&str` passed to the `#[serde(other)]` payload by `FromStr`, parsed into the primitive the
+/// payload asks for, like the `FromStr` of the earlier `#[serde(other)]` variants.
+pub struct ParseStrDeserializer<'a, E> {
+    value: &'a str,
+    marker: PhantomData<E>,
+}
+
+impl<'a, E> ParseStrDeserializer<'a, E> {
+    pub fn new(value: &'a str) -> Self {
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Falls back to the string, for the `invalid type` error of the payload.
+    fn parse<T, V>(self, visitor: V, visit: fn(V, T) -> Result<V::Value, E>) -> Result<V::Value, E>
+    where
+        T: FromStr,
+        V: Visitor<'a>,
+        E: de::Error,
+    {
+        match self.value.parse() {
+            Ok(value) => visit(visitor, value),
+            Err(_) => visitor.visit_str(self.value),
+        }
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, E>
+            where
+                V: Visitor<'de>,
+            {
+                self.parse(visitor, V::$visit)
+            }
+        )*
+    };
+}
+
+impl<'de, E> Deserializer<'de> for ParseStrDeserializer<'de, E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_str(self.value)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        let deserializer: StrDeserializer<'de, E> = self.value.into_deserializer();
+        deserializer.deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
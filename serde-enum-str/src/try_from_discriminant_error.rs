@@ -0,0 +1,45 @@
+use core::fmt;
+
+/// The error of the derived `TryFrom<i64>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromDiscriminantError {
+    value: i64,
+    enum_name: &'static str,
+}
+
+impl TryFromDiscriminantError {
+    pub fn new(value: i64, enum_name: &'static str) -> Self {
+        Self { value, enum_name }
+    }
+
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    pub fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+}
+
+impl fmt::Display for TryFromDiscriminantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} discriminant {}", self.enum_name, self.value)
+    }
+}
+
+impl core::error::Error for TryFromDiscriminantError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::string::ToString as _;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            TryFromDiscriminantError::new(-3, "Severity").to_string(),
+            "unknown Severity discriminant -3"
+        );
+    }
+}
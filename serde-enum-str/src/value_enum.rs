@@ -0,0 +1,21 @@
+use alloc::string::{String, ToString as _};
+use core::{fmt, str::FromStr};
+
+use clap::ValueEnum;
+
+/// The known names first, then `FromStr` for the `#[serde(pattern)]` and `#[serde(other)]` variants.
+pub fn value_enum_from_str<T>(input: &str, ignore_case: bool) -> Result<T, String>
+where
+    T: ValueEnum + FromStr,
+    T::Err: fmt::Display,
+{
+    let known = T::value_variants().iter().find(|value| {
+        value
+            .to_possible_value()
+            .is_some_and(|possible_value| possible_value.matches(input, ignore_case))
+    });
+    match known {
+        Some(value) => Ok(value.clone()),
+        None => input.parse().map_err(|err: T::Err| err.to_string()),
+    }
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "clap")]
+
+use core::str::FromStr;
+
+use clap::{Parser, ValueEnum};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Clone, PartialEq, Debug)]
+#[serde(rename_all = "kebab-case", value_enum)]
+enum Format {
+    #[serde(alias = "yml")]
+    Yaml,
+    Json,
+    PrettyJson,
+    #[serde(skip_deserializing)]
+    Internal,
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, Clone, PartialEq, Debug)]
+#[serde(rename_all = "lowercase", value_enum)]
+enum Codec {
+    Gzip,
+    Br,
+    #[serde(other)]
+    Other(String),
+}
+
+#[derive(Parser, Debug)]
+struct Cli {
+    #[arg(long, value_enum)]
+    format: Format,
+    #[arg(long, value_parser = <Codec as FromStr>::from_str)]
+    codec: Option<Codec>,
+}
+
+#[test]
+fn test_value_enum() {
+    assert_eq!(
+        Format::value_variants(),
+        &[Format::Yaml, Format::Json, Format::PrettyJson]
+    );
+    let possible_value = Format::Yaml.to_possible_value().unwrap();
+    assert_eq!(possible_value.get_name(), "yaml");
+    assert_eq!(
+        possible_value.get_name_and_aliases().collect::<Vec<_>>(),
+        ["yaml", "yml"]
+    );
+    assert!(Format::Internal.to_possible_value().is_none());
+
+    assert_eq!(
+        <Format as ValueEnum>::from_str("pretty-json", false).unwrap(),
+        Format::PrettyJson
+    );
+    assert_eq!(
+        <Format as ValueEnum>::from_str("YML", true).unwrap(),
+        Format::Yaml
+    );
+    assert!(<Format as ValueEnum>::from_str("internal", false).is_err());
+
+    assert_eq!(
+        <Codec as ValueEnum>::from_str("BR", true).unwrap(),
+        Codec::Br
+    );
+    assert_eq!(
+        <Codec as ValueEnum>::from_str("zstd", false).unwrap(),
+        Codec::Other("zstd".to_owned())
+    );
+}
+
+#[test]
+fn test_parser() {
+    let cli = Cli::try_parse_from(["app", "--format", "yml", "--codec", "gzip"]).unwrap();
+    assert_eq!(cli.format, Format::Yaml);
+    assert_eq!(cli.codec, Some(Codec::Gzip));
+
+    let cli = Cli::try_parse_from(["app", "--format", "json", "--codec", "zstd"]).unwrap();
+    assert_eq!(cli.codec, Some(Codec::Other("zstd".to_owned())));
+
+    assert!(Cli::try_parse_from(["app", "--format", "xml"]).is_err());
+
+    let help = Cli::try_parse_from(["app", "--help"])
+        .unwrap_err()
+        .to_string();
+    assert!(help.contains("[possible values: yaml, json, pretty-json]"));
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "diesel")]
+
+use diesel::{
+    connection::SimpleConnection, deserialize::FromSqlRow, expression::AsExpression, prelude::*,
+    sql_types::Text,
+};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, AsExpression, FromSqlRow, PartialEq, Debug)]
+#[diesel(sql_type = Text)]
+#[serde(rename_all = "snake_case", diesel)]
+enum Status {
+    #[serde(alias = "in-progress")]
+    InProgress,
+    Done,
+    #[serde(other)]
+    Other(String),
+}
+
+diesel::table! {
+    tasks (id) {
+        id -> Integer,
+        status -> Text,
+    }
+}
+
+#[derive(Queryable, Insertable, PartialEq, Debug)]
+#[diesel(table_name = tasks)]
+struct Task {
+    id: i32,
+    status: Status,
+}
+
+#[test]
+fn test_sqlite() {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    conn.batch_execute("CREATE TABLE tasks (id INTEGER PRIMARY KEY, status TEXT NOT NULL);")
+        .unwrap();
+
+    let rows = vec![
+        Task {
+            id: 1,
+            status: Status::InProgress,
+        },
+        Task {
+            id: 2,
+            status: Status::Other("blocked".to_owned()),
+        },
+    ];
+    for row in &rows {
+        diesel::insert_into(tasks::table)
+            .values(row)
+            .execute(&mut conn)
+            .unwrap();
+    }
+    conn.batch_execute("INSERT INTO tasks (id, status) VALUES (3, 'in-progress'), (4, 'done');")
+        .unwrap();
+
+    assert_eq!(
+        tasks::table
+            .select(tasks::status)
+            .filter(tasks::id.le(2))
+            .order(tasks::id)
+            .load::<String>(&mut conn)
+            .unwrap(),
+        vec!["in_progress", "blocked"]
+    );
+    assert_eq!(
+        tasks::table
+            .order(tasks::id)
+            .load::<Task>(&mut conn)
+            .unwrap()
+            .into_iter()
+            .map(|task| task.status)
+            .collect::<Vec<_>>(),
+        vec![
+            Status::InProgress,
+            Status::Other("blocked".to_owned()),
+            Status::InProgress,
+            Status::Done
+        ]
+    );
+    assert_eq!(
+        tasks::table
+            .filter(tasks::status.eq(Status::Done))
+            .select(tasks::id)
+            .load::<i32>(&mut conn)
+            .unwrap(),
+        vec![4]
+    );
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "postgres-types")]
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+#[serde(rename_all = "snake_case", postgres_types)]
+enum Status {
+    #[serde(alias = "in-progress")]
+    InProgress,
+    Done,
+    #[serde(other)]
+    Other(String),
+}
+
+fn to_sql(value: &Status, ty: &Type) -> BytesMut {
+    let mut buf = BytesMut::new();
+    assert!(matches!(
+        value.to_sql_checked(ty, &mut buf).unwrap(),
+        IsNull::No
+    ));
+    buf
+}
+
+#[test]
+fn test_to_sql() {
+    assert_eq!(
+        &to_sql(&Status::InProgress, &Type::TEXT)[..],
+        b"in_progress"
+    );
+    assert_eq!(&to_sql(&Status::Done, &Type::VARCHAR)[..], b"done");
+    assert_eq!(
+        &to_sql(&Status::Other("blocked".to_owned()), &Type::TEXT)[..],
+        b"blocked"
+    );
+
+    let mut buf = BytesMut::new();
+    assert!(Status::Done.to_sql_checked(&Type::INT4, &mut buf).is_err());
+}
+
+#[test]
+fn test_from_sql() {
+    assert_eq!(
+        Status::from_sql(&Type::TEXT, b"in_progress").unwrap(),
+        Status::InProgress
+    );
+    assert_eq!(
+        Status::from_sql(&Type::TEXT, b"in-progress").unwrap(),
+        Status::InProgress
+    );
+    assert_eq!(
+        Status::from_sql(&Type::VARCHAR, b"blocked").unwrap(),
+        Status::Other("blocked".to_owned())
+    );
+    assert!(<Status as FromSql>::accepts(&Type::TEXT));
+    assert!(!<Status as FromSql>::accepts(&Type::INT4));
+    assert!(Status::from_sql(&Type::TEXT, b"\xff").is_err());
+}
+
+#[test]
+fn test_round_trip() {
+    for status in [
+        Status::InProgress,
+        Status::Done,
+        Status::Other("blocked".to_owned()),
+    ] {
+        let buf = to_sql(&status, &Type::TEXT);
+        assert_eq!(Status::from_sql(&Type::TEXT, &buf).unwrap(), status);
+    }
+}
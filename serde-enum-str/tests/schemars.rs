@@ -0,0 +1,99 @@
+#![cfg(feature = "schemars")]
+
+use schemars::schema_for;
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use serde_json::json;
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "kebab-case", json_schema)]
+enum Format {
+    #[serde(alias = "yml")]
+    Yaml,
+    PrettyJson,
+    #[serde(skip_serializing)]
+    Internal,
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "kebab-case", json_schema(aliases))]
+enum FormatWithAliases {
+    #[serde(alias = "yml")]
+    Yaml,
+    PrettyJson,
+}
+
+/// Compression codec.
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "lowercase", json_schema)]
+enum Codec {
+    /// GNU zip.
+    Gzip,
+    Br,
+    #[serde(pattern = "x-{}")]
+    Custom(String),
+    /// Passed through as is.
+    #[serde(other)]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+#[serde(json_schema)]
+enum Level {
+    Low,
+    High,
+    #[serde(other)]
+    Other(String),
+}
+
+#[test]
+fn test_closed() {
+    assert_eq!(
+        schema_for!(Format).as_value(),
+        &json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Format",
+            "type": "string",
+            "enum": ["yaml", "pretty-json"],
+        })
+    );
+    assert_eq!(
+        schema_for!(FormatWithAliases).as_value(),
+        &json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "FormatWithAliases",
+            "type": "string",
+            "enum": ["yaml", "yml", "pretty-json"],
+        })
+    );
+}
+
+#[test]
+fn test_open() {
+    assert_eq!(
+        schema_for!(Level).as_value(),
+        &json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Level",
+            "type": "string",
+            "examples": ["Low", "High"],
+        })
+    );
+}
+
+#[test]
+fn test_descriptions() {
+    assert_eq!(
+        schema_for!(Codec).as_value(),
+        &json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Codec",
+            "description": "Compression codec.",
+            "anyOf": [
+                { "type": "string", "const": "gzip", "description": "GNU zip." },
+                { "type": "string", "const": "br" },
+                { "type": "string", "pattern": "^x-.*$" },
+                { "type": "string", "description": "Passed through as is." },
+            ],
+        })
+    );
+}
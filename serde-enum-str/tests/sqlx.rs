@@ -0,0 +1,77 @@
+#![cfg(feature = "sqlx")]
+
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use sqlx::{Row as _, sqlite::SqlitePoolOptions};
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+#[serde(rename_all = "snake_case", sqlx)]
+enum Status {
+    #[serde(alias = "in-progress")]
+    InProgress,
+    Done,
+    #[serde(other)]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+#[serde(sqlx)]
+enum Strict {
+    A,
+}
+
+#[tokio::test]
+async fn test_sqlite() {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::query("CREATE TABLE tasks (id INTEGER PRIMARY KEY, status TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    for (id, status) in [
+        (1, Status::InProgress),
+        (2, Status::Other("blocked".to_owned())),
+    ] {
+        sqlx::query("INSERT INTO tasks (id, status) VALUES (?, ?)")
+            .bind(id)
+            .bind(status)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    sqlx::query("INSERT INTO tasks (id, status) VALUES (3, 'in-progress'), (4, 'done')")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let rows = sqlx::query("SELECT id, status, status AS raw FROM tasks ORDER BY id")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(
+        rows.iter()
+            .map(|row| (row.get::<String, _>("raw"), row.get::<Status, _>("status")))
+            .collect::<Vec<_>>(),
+        vec![
+            ("in_progress".to_owned(), Status::InProgress),
+            ("blocked".to_owned(), Status::Other("blocked".to_owned())),
+            ("in-progress".to_owned(), Status::InProgress),
+            ("done".to_owned(), Status::Done),
+        ]
+    );
+
+    let (status,): (Status,) = sqlx::query_as("SELECT status FROM tasks WHERE id = 4")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(status, Status::Done);
+
+    let err = sqlx::query_as::<_, (Strict,)>("SELECT status FROM tasks WHERE id = 4")
+        .fetch_one(&pool)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, sqlx::Error::ColumnDecode { .. }), "{err:?}");
+}
@@ -308,3 +308,2012 @@ mod with_box_str {
         Other(Box<str>),
     }
 }
+
+#[cfg(test)]
+mod with_generics {
+    use super::*;
+
+    use core::fmt::Display;
+    use std::borrow::Cow;
+
+    use serde::{Serialize, de::DeserializeOwned};
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "kebab-case")]
+    enum Region<S>
+    where
+        S: AsRef<str>,
+    {
+        UsEast,
+        EuWest,
+        #[serde(other)]
+        Other(S),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Foo<'a> {
+        A,
+        #[serde(other)]
+        Other(Cow<'a, str>),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(bound = "S: Serialize + Display + DeserializeOwned")]
+    enum Bar<S> {
+        A,
+        #[serde(other)]
+        Other(Box<S>),
+    }
+
+    #[test]
+    fn test_ser() {
+        assert_eq!(
+            serde_json::to_string(&Region::<String>::UsEast).unwrap(),
+            r#""us-east""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Region::Other(Box::<str>::from("ap-south"))).unwrap(),
+            r#""ap-south""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Foo::Other(Cow::Borrowed("x"))).unwrap(),
+            r#""x""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Bar::Other(Box::new(1_u8))).unwrap(),
+            r#"1"#
+        );
+    }
+
+    #[test]
+    fn test_de() {
+        assert_eq!(
+            serde_json::from_str::<Region<String>>(r#""eu-west""#).unwrap(),
+            Region::EuWest
+        );
+        assert_eq!(
+            serde_json::from_str::<Region<Box<str>>>(r#""ap-south""#).unwrap(),
+            Region::Other("ap-south".into())
+        );
+        assert_eq!(
+            serde_json::from_str::<Foo>(r#""x""#).unwrap(),
+            Foo::Other(Cow::Owned("x".to_owned()))
+        );
+        assert_eq!(
+            serde_json::from_str::<Bar<String>>(r#""A""#).unwrap(),
+            Bar::A
+        );
+    }
+
+    #[test]
+    fn test_to_string() {
+        assert_eq!(Region::<String>::UsEast.to_string(), "us-east");
+        assert_eq!(Region::Other("ap-south".to_owned()).to_string(), "ap-south");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("us-east".parse::<Region<String>>().unwrap(), Region::UsEast);
+        assert_eq!(
+            Region::<String>::try_from("ap-south").unwrap(),
+            Region::Other("ap-south".to_owned())
+        );
+        assert_eq!(
+            "z".parse::<Bar<String>>().unwrap(),
+            Bar::Other(Box::new("z".to_owned()))
+        );
+    }
+}
+
+#[cfg(test)]
+mod de_visitor {
+    use super::*;
+
+    use std::net::Ipv4Addr;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case")]
+    enum Foo {
+        #[serde(alias = "aa")]
+        A,
+        #[serde(skip_deserializing)]
+        B,
+        C,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Bar {
+        A,
+        #[serde(other)]
+        Other(Ipv4Addr),
+    }
+
+    #[test]
+    fn test_de_error() {
+        assert_eq!(serde_json::to_string(&Foo::B).unwrap(), r#""b""#);
+        assert_eq!(
+            serde_json::from_str::<Foo>(r#""b""#)
+                .err()
+                .unwrap()
+                .to_string(),
+            "unknown variant `b`, expected `a` or `c` at line 1 column 3"
+        );
+        assert_eq!(
+            serde_json::from_str::<Foo>("1").err().unwrap().to_string(),
+            "invalid type: integer `1`, expected variant identifier at line 1 column 1"
+        );
+        assert_eq!(
+            serde_json::from_str::<Bar>(r#""x""#)
+                .err()
+                .unwrap()
+                .to_string(),
+            "invalid IPv4 address syntax at line 1 column 3"
+        );
+    }
+
+    #[test]
+    fn test_de_owned_and_borrowed() {
+        let value = serde_json::Value::String("aa".to_owned());
+        assert_eq!(serde_json::from_value::<Foo>(value).unwrap(), Foo::A);
+        let value = serde_json::Value::String("127.0.0.1".to_owned());
+        assert_eq!(
+            serde_json::from_value::<Bar>(value).unwrap(),
+            Bar::Other(Ipv4Addr::LOCALHOST)
+        );
+        assert_eq!(
+            serde_json::from_slice::<Bar>(br#""127.0.0.1""#).unwrap(),
+            Bar::Other(Ipv4Addr::LOCALHOST)
+        );
+    }
+
+    #[test]
+    fn test_de_bytes() {
+        use serde::de::value::BytesDeserializer;
+
+        let de = BytesDeserializer::<serde::de::value::Error>::new(b"aa");
+        assert_eq!(
+            <Foo as serde::Deserialize>::deserialize(de).unwrap(),
+            Foo::A
+        );
+        let de = BytesDeserializer::<serde::de::value::Error>::new(b"127.0.0.1");
+        assert_eq!(
+            <Bar as serde::Deserialize>::deserialize(de).unwrap(),
+            Bar::Other(Ipv4Addr::LOCALHOST)
+        );
+        let de = BytesDeserializer::<serde::de::value::Error>::new(b"\xff");
+        assert!(<Bar as serde::Deserialize>::deserialize(de).is_err());
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case")]
+    enum Code {
+        Ok,
+        #[serde(other)]
+        Other(u16),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Switch {
+        Auto,
+        #[serde(other)]
+        Manual(bool),
+    }
+
+    #[test]
+    fn test_de_primitive_payload() {
+        assert_eq!(serde_json::from_str::<Code>("5").unwrap(), Code::Other(5));
+        assert_eq!(serde_json::from_str::<Code>(r#""ok""#).unwrap(), Code::Ok);
+        assert_eq!(
+            serde_json::from_str::<Code>("70000")
+                .err()
+                .unwrap()
+                .to_string(),
+            "invalid value: integer `70000`, expected u16 at line 1 column 5"
+        );
+        assert!(serde_json::from_str::<Code>("-1").is_err());
+        assert!(serde_json::from_str::<Code>(r#""x""#).is_err());
+        assert_eq!(serde_json::to_string(&Code::Other(5)).unwrap(), "5");
+
+        assert_eq!(
+            serde_json::from_str::<Switch>("true").unwrap(),
+            Switch::Manual(true)
+        );
+        assert_eq!(
+            serde_json::from_str::<Switch>(r#""Auto""#).unwrap(),
+            Switch::Auto
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Bar>("1").err().unwrap().to_string(),
+            "invalid type: integer `1`, expected IPv4 address at line 1 column 1"
+        );
+    }
+
+    #[test]
+    fn test_from_str_primitive_payload() {
+        assert_eq!("5".parse::<Code>().unwrap(), Code::Other(5));
+        assert_eq!("ok".parse::<Code>().unwrap(), Code::Ok);
+        assert!("x".parse::<Code>().is_err());
+        assert!("70000".parse::<Code>().is_err());
+        assert_eq!("false".parse::<Switch>().unwrap(), Switch::Manual(false));
+    }
+}
+
+#[cfg(test)]
+mod with_borrowed_other {
+    use super::*;
+
+    use std::borrow::Cow;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase")]
+    enum Level<'a> {
+        Info,
+        Warn,
+        #[serde(other)]
+        Other(&'a str),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase")]
+    enum LevelCow<'a> {
+        Info,
+        #[serde(other, borrow)]
+        Other(Cow<'a, str>),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Foo<'a> {
+        A,
+        #[serde(other, borrow = "'a")]
+        Other(Cow<'a, str>),
+    }
+
+    #[test]
+    fn test_de() {
+        let json = br#"["info", "debug"]"#.to_vec();
+        let levels = serde_json::from_slice::<Vec<Level<'_>>>(&json).unwrap();
+        assert_eq!(levels, vec![Level::Info, Level::Other("debug")]);
+        assert!(serde_json::from_str::<Level<'_>>(r#""de\"bug""#).is_err());
+
+        let levels = serde_json::from_slice::<Vec<LevelCow<'_>>>(&json).unwrap();
+        assert!(matches!(levels[1], LevelCow::Other(Cow::Borrowed("debug"))));
+        assert_eq!(
+            serde_json::from_str::<LevelCow<'_>>(r#""de\"bug""#).unwrap(),
+            LevelCow::Other(Cow::Owned(r#"de"bug"#.to_owned()))
+        );
+        let value = serde_json::Value::String("debug".to_owned());
+        assert!(matches!(
+            <LevelCow<'_> as serde::Deserialize>::deserialize(value).unwrap(),
+            LevelCow::Other(Cow::Owned(_))
+        ));
+
+        assert_eq!(
+            serde_json::from_slice::<Foo<'_>>(br#""A""#).unwrap(),
+            Foo::A
+        );
+        assert!(matches!(
+            serde_json::from_slice::<Foo<'_>>(br#""z""#).unwrap(),
+            Foo::Other(Cow::Borrowed("z"))
+        ));
+    }
+
+    #[test]
+    fn test_ser() {
+        assert_eq!(
+            serde_json::to_string(&Level::Other("debug")).unwrap(),
+            r#""debug""#
+        );
+        assert_eq!(Level::Warn.to_string(), "warn");
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let s = String::from("debug");
+        assert_eq!(Level::try_from(s.as_str()).unwrap(), Level::Other("debug"));
+        assert!(matches!(
+            LevelCow::try_from(s.as_str()).unwrap(),
+            LevelCow::Other(Cow::Borrowed("debug"))
+        ));
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let bytes = b"debug".to_vec();
+        assert_eq!(Level::try_from(&b"warn"[..]).unwrap(), Level::Warn);
+        assert_eq!(
+            Level::try_from(bytes.as_slice()).unwrap(),
+            Level::Other("debug")
+        );
+        assert!(matches!(
+            LevelCow::try_from(bytes.as_slice()).unwrap(),
+            LevelCow::Other(Cow::Borrowed("debug"))
+        ));
+        assert!(Level::try_from(&b"\xff"[..]).is_err());
+
+        assert_eq!(
+            Level::from_bytes(bytes.as_slice()).unwrap(),
+            Level::Other("debug")
+        );
+        assert!(matches!(
+            LevelCow::from_bytes(bytes.as_slice()).unwrap(),
+            LevelCow::Other(Cow::Borrowed("debug"))
+        ));
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, PartialEq, Debug)]
+    #[serde(transparent)]
+    struct Name<'a, 'b> {
+        name: &'a str,
+        #[serde(skip)]
+        marker: core::marker::PhantomData<&'b ()>,
+    }
+    impl core::fmt::Display for Name<'_, '_> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str(self.name)
+        }
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Pair<'a, 'b> {
+        A,
+        #[serde(other, borrow)]
+        Other(Name<'a, 'b>),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(char)]
+    enum Side<'a> {
+        #[serde(rename = "b")]
+        Buy,
+        #[serde(other, borrow)]
+        Other(Cow<'a, str>),
+    }
+
+    #[test]
+    fn test_multiple_lifetimes() {
+        let s = String::from("x");
+        assert_eq!(Pair::try_from("A").unwrap(), Pair::A);
+        assert_eq!(
+            Pair::try_from(s.as_str()).unwrap(),
+            Pair::Other(Name {
+                name: "x",
+                marker: core::marker::PhantomData
+            })
+        );
+        assert!(Pair::from_bytes(b"A").is_ok());
+    }
+
+    #[test]
+    fn test_try_from_char() {
+        assert_eq!(Side::try_from('b').unwrap(), Side::Buy);
+        assert_eq!(
+            Side::try_from('s').unwrap(),
+            Side::Other(Cow::Owned("s".to_owned()))
+        );
+    }
+}
+
+#[cfg(test)]
+mod enum_str {
+    use super::*;
+
+    use serde_enum_str::EnumStr;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case")]
+    enum Foo {
+        A,
+        #[serde(rename = "B")]
+        B,
+        #[serde(skip_serializing)]
+        C,
+        #[serde(other)]
+        Other,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "kebab-case")]
+    enum Region<S> {
+        UsEast,
+        EuWest,
+        #[serde(other)]
+        Other(S),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase")]
+    enum Level<'a> {
+        Info,
+        #[serde(other)]
+        Other(&'a str),
+    }
+
+    const FOO_B: &str = Foo::B.as_str();
+
+    fn names<T: EnumStr + 'static>() -> Vec<Option<&'static str>> {
+        T::all().iter().map(EnumStr::as_str).collect()
+    }
+
+    #[test]
+    fn test_as_str() {
+        assert_eq!(Foo::A.as_str(), "a");
+        assert_eq!(FOO_B, "B");
+        assert_eq!(Foo::C.as_str(), "c");
+        assert_eq!(Foo::Other.as_str(), "other");
+
+        assert_eq!(Region::<String>::UsEast.as_str(), Some("us-east"));
+        assert_eq!(Region::Other("ap-south".to_owned()).as_str(), None);
+
+        assert_eq!(Level::Info.as_str(), Some("info"));
+        assert_eq!(EnumStr::as_str(&Level::Other("debug")), None);
+    }
+
+    #[test]
+    fn test_variants_and_all() {
+        assert_eq!(Foo::VARIANTS, &["a", "B", "other"]);
+        assert_eq!(Foo::ALL, &[Foo::A, Foo::B, Foo::Other]);
+        assert_eq!(<Foo as EnumStr>::VARIANTS, Foo::VARIANTS);
+
+        assert_eq!(Region::<String>::VARIANTS, &["us-east", "eu-west"]);
+        assert_eq!(Region::<String>::ALL, &[Region::UsEast, Region::EuWest]);
+
+        assert_eq!(<Level<'static> as EnumStr>::VARIANTS, &["info"]);
+        assert_eq!(Level::ALL, &[Level::Info]);
+    }
+
+    #[test]
+    fn test_generic_code() {
+        assert_eq!(names::<Foo>(), vec![Some("a"), Some("B"), Some("other")]);
+        assert_eq!(
+            names::<Region<String>>(),
+            vec![Some("us-east"), Some("eu-west")]
+        );
+    }
+}
+
+#[cfg(test)]
+mod with_ascii_case_insensitive {
+    use super::*;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase", ascii_case_insensitive)]
+    enum Status {
+        #[serde(alias = "enabled")]
+        Active,
+        Inactive,
+        #[serde(ascii_case_insensitive = false)]
+        Pending,
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Foo {
+        #[serde(ascii_case_insensitive)]
+        A,
+        B,
+    }
+
+    #[test]
+    fn test_de() {
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""ACTIVE""#).unwrap(),
+            Status::Active
+        );
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""Active""#).unwrap(),
+            Status::Active
+        );
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""Enabled""#).unwrap(),
+            Status::Active
+        );
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""inACTIVE""#).unwrap(),
+            Status::Inactive
+        );
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""pending""#).unwrap(),
+            Status::Pending
+        );
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""PENDING""#).unwrap(),
+            Status::Other("PENDING".to_owned())
+        );
+
+        assert_eq!(serde_json::from_str::<Foo>(r#""a""#).unwrap(), Foo::A);
+        assert!(serde_json::from_str::<Foo>(r#""b""#).is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("INACTIVE".parse::<Status>().unwrap(), Status::Inactive);
+        assert_eq!(Status::try_from("aCtIvE").unwrap(), Status::Active);
+        assert_eq!(
+            Status::try_from("ENABLED".to_owned()).unwrap(),
+            Status::Active
+        );
+    }
+
+    #[test]
+    fn test_ser() {
+        assert_eq!(
+            serde_json::to_string(&Status::Active).unwrap(),
+            r#""active""#
+        );
+        assert_eq!(Status::Inactive.to_string(), "inactive");
+    }
+}
+
+#[cfg(test)]
+mod parse_enum_error {
+    use super::*;
+
+    use std::net::Ipv4Addr;
+
+    use serde_enum_str::ParseEnumError;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "kebab-case")]
+    enum Region {
+        UsEast,
+        #[serde(alias = "eu")]
+        EuWest,
+        #[serde(skip_deserializing)]
+        ApSouth,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Addr {
+        A,
+        #[serde(other)]
+        Other(Ipv4Addr),
+    }
+
+    #[test]
+    fn test_from_str() {
+        let err = "xx".parse::<Region>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseEnumError::new("xx", "Region", &["us-east", "eu-west"])
+        );
+        assert_eq!(err.input(), "xx");
+        assert_eq!(err.enum_name(), "Region");
+        assert_eq!(err.expected(), &["us-east", "eu-west"]);
+        assert_eq!(
+            err.to_string(),
+            "unknown Region `xx`; expected one of us-east, eu-west"
+        );
+        assert_eq!(
+            Region::try_from("ap-south").unwrap_err().input(),
+            "ap-south"
+        );
+        assert_eq!(Region::try_from("eu".to_owned()).unwrap(), Region::EuWest);
+        assert_eq!(Region::ApSouth.to_string(), "ap-south");
+
+        let err = "x".parse::<Addr>().unwrap_err();
+        assert_eq!(err.reason(), Some("invalid IPv4 address syntax"));
+        assert_eq!(
+            err.to_string(),
+            "invalid Addr `x`: invalid IPv4 address syntax"
+        );
+
+        let err: Box<dyn std::error::Error> = Box::new(err);
+        assert!(err.to_string().starts_with("invalid Addr"));
+    }
+}
+
+#[cfg(test)]
+mod with_compact_repr {
+    use super::*;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase", compact_repr = "u8")]
+    #[repr(u8)]
+    enum Level {
+        Low,
+        Mid = 10,
+        High,
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(compact_repr = "i16")]
+    enum Foo {
+        A = -1,
+        B,
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(compact_repr = "u32")]
+    enum Bar {
+        A,
+        B,
+    }
+
+    #[test]
+    fn test_human_readable() {
+        assert_eq!(serde_json::to_string(&Level::Mid).unwrap(), r#""mid""#);
+        assert_eq!(
+            serde_json::from_str::<Level>(r#""high""#).unwrap(),
+            Level::High
+        );
+        assert_eq!(
+            serde_json::to_string(&Level::Other("x".to_owned())).unwrap(),
+            r#""x""#
+        );
+    }
+
+    #[test]
+    fn test_bincode() {
+        assert_eq!(bincode::serialize(&Level::Low).unwrap(), vec![0]);
+        assert_eq!(bincode::serialize(&Level::Mid).unwrap(), vec![10]);
+        assert_eq!(bincode::serialize(&Level::High).unwrap(), vec![11]);
+
+        for level in [
+            Level::Low,
+            Level::Mid,
+            Level::High,
+            Level::Other("extreme".to_owned()),
+        ] {
+            let bytes = bincode::serialize(&level).unwrap();
+            assert_eq!(bincode::deserialize::<Level>(&bytes).unwrap(), level);
+        }
+        assert!(bincode::deserialize::<Level>(&[1]).is_err());
+
+        assert_eq!(bincode::serialize(&Foo::A).unwrap(), (-1_i16).to_le_bytes());
+        assert_eq!(bincode::serialize(&Foo::B).unwrap(), 0_i16.to_le_bytes());
+        assert_eq!(
+            bincode::deserialize::<Foo>(&0_i16.to_le_bytes()).unwrap(),
+            Foo::B
+        );
+        assert_eq!(
+            bincode::deserialize::<Foo>(&7_i16.to_le_bytes()).unwrap(),
+            Foo::Unknown
+        );
+
+        assert_eq!(bincode::serialize(&Bar::B).unwrap(), 1_u32.to_le_bytes());
+        assert_eq!(
+            bincode::deserialize::<Bar>(&1_u32.to_le_bytes()).unwrap(),
+            Bar::B
+        );
+        assert!(bincode::deserialize::<Bar>(&2_u32.to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_rmp() {
+        assert_eq!(rmp_serde::to_vec(&Level::Mid).unwrap(), vec![10]);
+
+        for level in [
+            Level::Low,
+            Level::Mid,
+            Level::High,
+            Level::Other("extreme".to_owned()),
+        ] {
+            let bytes = rmp_serde::to_vec(&level).unwrap();
+            assert_eq!(rmp_serde::from_slice::<Level>(&bytes).unwrap(), level);
+        }
+
+        // Both forms are accepted.
+        let bytes = rmp_serde::to_vec("high").unwrap();
+        assert_eq!(rmp_serde::from_slice::<Level>(&bytes).unwrap(), Level::High);
+        let bytes = rmp_serde::to_vec("extreme").unwrap();
+        assert_eq!(
+            rmp_serde::from_slice::<Level>(&bytes).unwrap(),
+            Level::Other("extreme".to_owned())
+        );
+    }
+}
+
+#[cfg(test)]
+mod with_accept_discriminant {
+    use super::*;
+
+    use core::convert::TryFrom as _;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase", accept_discriminant)]
+    enum Severity {
+        Info = 1,
+        Warning,
+        #[serde(alias = "err")]
+        Error = 5,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase", accept_discriminant = "numeric_string")]
+    #[repr(u8)]
+    enum Level {
+        Low = 1,
+        High = 2,
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Code {
+        A = -1,
+        B = 7,
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[test]
+    fn test_de() {
+        assert_eq!(
+            serde_json::from_str::<Severity>(r#""warning""#).unwrap(),
+            Severity::Warning
+        );
+        assert_eq!(
+            serde_json::from_str::<Severity>("2").unwrap(),
+            Severity::Warning
+        );
+        assert_eq!(
+            serde_json::from_str::<Severity>("5").unwrap(),
+            Severity::Error
+        );
+        assert!(serde_json::from_str::<Severity>("3").is_err());
+        assert!(serde_json::from_str::<Severity>("-1").is_err());
+        assert!(serde_json::from_str::<Severity>(r#""2""#).is_err());
+
+        assert_eq!(serde_json::from_str::<Level>("2").unwrap(), Level::High);
+        assert_eq!(
+            serde_json::from_str::<Level>(r#""2""#).unwrap(),
+            Level::High
+        );
+        assert_eq!(
+            serde_json::from_str::<Level>(r#""3""#).unwrap(),
+            Level::Other("3".to_owned())
+        );
+        assert!(serde_json::from_str::<Level>("3").is_err());
+        assert_eq!("1".parse::<Level>().unwrap(), Level::Low);
+        assert_eq!("9".parse::<Level>().unwrap(), Level::Other("9".to_owned()));
+
+        // Without the attribute, only names are accepted.
+        assert!(serde_json::from_str::<Code>("7").is_err());
+        assert_eq!("7".parse::<Code>().unwrap(), Code::Unknown);
+    }
+
+    #[test]
+    fn test_ser() {
+        assert_eq!(
+            serde_json::to_string(&Severity::Warning).unwrap(),
+            r#""warning""#
+        );
+    }
+
+    #[test]
+    fn test_try_from_i64() {
+        assert_eq!(Severity::try_from(2_i64).unwrap(), Severity::Warning);
+        let err = Severity::try_from(3_i64).unwrap_err();
+        assert_eq!(err.value(), 3);
+        assert_eq!(err.to_string(), "unknown Severity discriminant 3");
+
+        assert_eq!(Level::try_from(1_i64).unwrap(), Level::Low);
+        assert!(Level::try_from(3_i64).is_err());
+
+        assert_eq!(Code::try_from(-1_i64).unwrap(), Code::A);
+        assert_eq!(Code::try_from(8_i64).unwrap(), Code::Unknown);
+    }
+
+    #[test]
+    fn test_into_i64() {
+        assert_eq!(i64::from(Severity::Info), 1);
+        assert_eq!(i64::from(Severity::Warning), 2);
+        assert_eq!(i64::from(Severity::Error), 5);
+
+        assert_eq!(i64::from(Code::A), -1);
+        assert_eq!(i64::from(Code::B), 7);
+        assert_eq!(i64::from(Code::Unknown), 8);
+    }
+}
+
+#[cfg(test)]
+mod with_pattern {
+    use super::*;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase")]
+    enum Encoding {
+        Gzip,
+        Br,
+        #[serde(pattern = "x-{}")]
+        Custom(String),
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Version {
+        #[serde(rename = "latest")]
+        Latest,
+        #[serde(pattern = "v{}")]
+        Major(u32),
+        #[serde(pattern = "v{}-beta")]
+        Beta(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Tagged<T> {
+        None,
+        #[serde(pattern = "<{}>")]
+        Some(T),
+    }
+
+    #[test]
+    fn test_de() {
+        assert_eq!(
+            serde_json::from_str::<Encoding>(r#""gzip""#).unwrap(),
+            Encoding::Gzip
+        );
+        assert_eq!(
+            serde_json::from_str::<Encoding>(r#""x-custom-foo""#).unwrap(),
+            Encoding::Custom("custom-foo".to_owned())
+        );
+        assert_eq!(
+            serde_json::from_str::<Encoding>(r#""deflate""#).unwrap(),
+            Encoding::Other("deflate".to_owned())
+        );
+        assert_eq!(
+            "x-".parse::<Encoding>().unwrap(),
+            Encoding::Custom("".to_owned())
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Version>(r#""latest""#).unwrap(),
+            Version::Latest
+        );
+        assert_eq!(
+            serde_json::from_str::<Version>(r#""v2""#).unwrap(),
+            Version::Major(2)
+        );
+        // `Major` rejects the inner part, so the next pattern is tried.
+        assert_eq!(
+            serde_json::from_str::<Version>(r#""v3-beta""#).unwrap(),
+            Version::Beta("3".to_owned())
+        );
+        assert!(serde_json::from_str::<Version>(r#""vx""#).is_err());
+        assert!("2".parse::<Version>().is_err());
+
+        assert_eq!("<7>".parse::<Tagged<u8>>().unwrap(), Tagged::Some(7));
+        assert_eq!(
+            serde_json::from_str::<Tagged<u8>>(r#""None""#).unwrap(),
+            Tagged::None
+        );
+    }
+
+    #[test]
+    fn test_ser() {
+        assert_eq!(
+            serde_json::to_string(&Encoding::Custom("custom-foo".to_owned())).unwrap(),
+            r#""x-custom-foo""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Version::Major(1)).unwrap(),
+            r#""v1""#
+        );
+        assert_eq!(Version::Beta("2".to_owned()).to_string(), "v2-beta");
+        assert_eq!(Tagged::Some(7).to_string(), "<7>");
+
+        assert_eq!(Version::Latest.as_str(), Some("latest"));
+        assert_eq!(Version::Major(1).as_str(), None);
+        assert_eq!(Version::VARIANTS, &["latest"]);
+    }
+}
+
+#[cfg(test)]
+mod with_remote {
+    use super::*;
+
+    use core::fmt;
+
+    use serde::{Deserialize, Serialize};
+
+    mod protocol {
+        #[derive(PartialEq, Debug)]
+        pub enum Kind {
+            Request,
+            Response,
+            Unknown(String),
+        }
+    }
+    use protocol::Kind;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str)]
+    #[serde(remote = "protocol::Kind", rename_all = "lowercase")]
+    #[allow(dead_code)]
+    enum KindDef {
+        #[serde(alias = "req")]
+        Request,
+        Response,
+        #[serde(other)]
+        Unknown(String),
+    }
+
+    #[derive(Deserialize, Serialize, PartialEq, Debug)]
+    struct Message {
+        #[serde(with = "KindDef")]
+        kind: Kind,
+    }
+
+    mod token {
+        #[derive(PartialEq, Debug)]
+        pub enum Token<'a> {
+            Plus,
+            Word(&'a str),
+        }
+
+        #[derive(PartialEq, Debug)]
+        pub enum Side {
+            Buy,
+            Sell,
+        }
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str)]
+    #[serde(remote = "token::Token")]
+    #[allow(dead_code)]
+    enum TokenDef<'a> {
+        #[serde(rename = "+")]
+        Plus,
+        #[serde(other)]
+        Word(&'a str),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str)]
+    #[serde(remote = "token::Side", char)]
+    #[allow(dead_code)]
+    enum SideDef {
+        #[serde(rename = "b")]
+        Buy,
+        #[serde(rename = "s")]
+        Sell,
+    }
+
+    #[test]
+    fn test_borrowed_and_char() {
+        let s = String::from("word");
+        assert_eq!(TokenDef::from_str("+").unwrap(), token::Token::Plus);
+        assert_eq!(
+            TokenDef::from_str(s.as_str()).unwrap(),
+            token::Token::Word("word")
+        );
+        assert_eq!(
+            TokenDef::from_bytes(s.as_bytes()).unwrap(),
+            token::Token::Word("word")
+        );
+
+        assert_eq!(SideDef::from_char('s').unwrap(), token::Side::Sell);
+        assert!(SideDef::from_char('x').is_err());
+    }
+
+    struct DisplayKind<'a>(&'a Kind);
+    impl fmt::Display for DisplayKind<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            KindDef::fmt(self.0, f)
+        }
+    }
+
+    #[test]
+    fn test_with() {
+        let message = Message {
+            kind: Kind::Request,
+        };
+        assert_eq!(
+            serde_json::to_string(&message).unwrap(),
+            r#"{"kind":"request"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Message>(r#"{"kind":"req"}"#).unwrap(),
+            message
+        );
+        assert_eq!(
+            serde_json::from_str::<Message>(r#"{"kind":"ping"}"#).unwrap(),
+            Message {
+                kind: Kind::Unknown("ping".to_owned())
+            }
+        );
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(DisplayKind(&Kind::Response).to_string(), "response");
+        assert_eq!(
+            DisplayKind(&Kind::Unknown("ping".to_owned())).to_string(),
+            "ping"
+        );
+        assert_eq!(KindDef::from_str("req").unwrap(), Kind::Request);
+        assert_eq!(KindDef::from_bytes(b"req").unwrap(), Kind::Request);
+        assert_eq!(
+            KindDef::from_str("ping").unwrap(),
+            Kind::Unknown("ping".to_owned())
+        );
+        assert_eq!(KindDef::as_str(&Kind::Response), Some("response"));
+        assert_eq!(KindDef::VARIANTS, &["request", "response"]);
+    }
+}
+
+#[cfg(test)]
+mod with_multiple_other {
+    use super::*;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case")]
+    enum Status {
+        Ok,
+        NotFound,
+        #[serde(other)]
+        Code(u16),
+        #[serde(other)]
+        Signed(i64),
+        #[serde(other)]
+        Unknown(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Level {
+        Low,
+        #[serde(other)]
+        Numeric(u8),
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[test]
+    fn test_de() {
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""not_found""#).unwrap(),
+            Status::NotFound
+        );
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""404""#).unwrap(),
+            Status::Code(404)
+        );
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""-1""#).unwrap(),
+            Status::Signed(-1)
+        );
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""gone""#).unwrap(),
+            Status::Unknown("gone".to_owned())
+        );
+        assert_eq!("70000".parse::<Status>().unwrap(), Status::Signed(70000));
+
+        assert_eq!("3".parse::<Level>().unwrap(), Level::Numeric(3));
+        assert_eq!(
+            serde_json::from_str::<Level>(r#""high""#).unwrap(),
+            Level::Unknown
+        );
+    }
+
+    #[test]
+    fn test_ser() {
+        assert_eq!(
+            serde_json::to_string(&Status::Code(404)).unwrap(),
+            r#""404""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Status::Signed(-1)).unwrap(),
+            r#""-1""#
+        );
+        assert_eq!(
+            serde_json::to_string(&Status::Unknown("gone".to_owned())).unwrap(),
+            r#""gone""#
+        );
+        assert_eq!(Status::Code(500).to_string(), "500");
+        assert_eq!(Level::Numeric(3).to_string(), "3");
+        assert_eq!(Level::Unknown.to_string(), "Unknown");
+
+        assert_eq!(Status::Code(404).as_str(), None);
+        assert_eq!(Status::VARIANTS, &["ok", "not_found"]);
+    }
+}
+
+#[cfg(test)]
+mod enum_str_set {
+    use super::*;
+
+    use serde_enum_str::{EnumStrSet, Space};
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, Clone, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase")]
+    enum Permission {
+        Read,
+        #[serde(alias = "modify")]
+        Write,
+        #[serde(rename = "admin")]
+        Administrator,
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Flag {
+        A,
+        B,
+    }
+
+    #[derive(serde::Deserialize, serde::Serialize, PartialEq, Debug)]
+    struct Token {
+        permissions: EnumStrSet<Permission>,
+        scope: EnumStrSet<Flag, Space>,
+    }
+
+    #[test]
+    fn test_de() {
+        let token = serde_json::from_str::<Token>(
+            r#"{"permissions": " admin, read,modify,,read ,x-beta", "scope": "B  A"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            token.permissions.as_slice(),
+            &[
+                Permission::Read,
+                Permission::Write,
+                Permission::Administrator,
+                Permission::Other("x-beta".to_owned())
+            ]
+        );
+        assert_eq!(token.scope.as_slice(), &[Flag::A, Flag::B]);
+
+        assert!("".parse::<EnumStrSet<Flag>>().unwrap().is_empty());
+
+        let err =
+            serde_json::from_str::<Token>(r#"{"permissions": "", "scope": "A C"}"#).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("unknown Flag `C`; expected one of A, B")
+        );
+    }
+
+    #[test]
+    fn test_ser() {
+        let token = Token {
+            permissions: [
+                Permission::Other("x-beta".to_owned()),
+                Permission::Administrator,
+                Permission::Read,
+                Permission::Administrator,
+            ]
+            .into_iter()
+            .collect(),
+            scope: EnumStrSet::new(),
+        };
+        assert_eq!(
+            serde_json::to_string(&token).unwrap(),
+            r#"{"permissions":"read,admin,x-beta","scope":""}"#
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut set = EnumStrSet::<Flag>::new();
+        assert!(set.insert(Flag::B));
+        assert!(set.insert(Flag::A));
+        assert!(!set.insert(Flag::B));
+        assert_eq!(set.to_string(), "A,B");
+        assert!(set.contains(&Flag::A));
+        assert!(set.remove(&Flag::A));
+        assert!(!set.remove(&Flag::A));
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.into_vec(), [Flag::B]);
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Level {
+        Low,
+        #[serde(skip_serializing)]
+        Legacy,
+    }
+
+    #[test]
+    fn test_ser_unwritable() {
+        use std::fmt::Write as _;
+
+        let set = [Level::Low, Level::Legacy]
+            .into_iter()
+            .collect::<EnumStrSet<Level>>();
+        assert!(write!(String::new(), "{set}").is_err());
+        assert_eq!(
+            serde_json::to_string(&set).unwrap_err().to_string(),
+            "member `Legacy` cannot be serialized"
+        );
+
+        let set = [Permission::Read, Permission::Other("a,b".to_owned())]
+            .into_iter()
+            .collect::<EnumStrSet<Permission>>();
+        assert!(write!(String::new(), "{set}").is_err());
+        assert_eq!(
+            serde_json::to_string(&set).unwrap_err().to_string(),
+            "member `a,b` contains the separator `,`"
+        );
+        let set = [Permission::Other("a,b".to_owned())]
+            .into_iter()
+            .collect::<EnumStrSet<Permission, Space>>();
+        assert_eq!(set.to_string(), "a,b");
+    }
+}
+
+#[cfg(test)]
+mod with_display {
+    use super::*;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case", display_rename_all = "kebab-case")]
+    enum Region {
+        #[serde(display = "US East (N. Virginia)")]
+        UsEast1,
+        EuWest1,
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case", accept_display)]
+    enum Status {
+        #[serde(display = "In progress", ascii_case_insensitive)]
+        InProgress,
+        #[serde(display = "done")]
+        Done,
+        #[serde(skip_serializing)]
+        Archived,
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case")]
+    enum Legacy {
+        #[serde(skip_serializing, rename = "bee")]
+        OldName,
+        #[serde(skip_serializing, display = "Old")]
+        Old,
+        New,
+    }
+
+    #[test]
+    fn test_display_skip_serializing() {
+        // Like before `#[serde(display)]`, `rename` does not apply to a variant that is never
+        // serialized.
+        assert_eq!(Legacy::OldName.to_string(), "old_name");
+        assert_eq!(Legacy::Old.to_string(), "Old");
+        assert_eq!(Legacy::New.to_string(), "new");
+        assert_eq!("bee".parse::<Legacy>().unwrap(), Legacy::OldName);
+        assert!(serde_json::to_string(&Legacy::OldName).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Region::UsEast1.to_string(), "US East (N. Virginia)");
+        assert_eq!(Region::EuWest1.to_string(), "eu-west1");
+        assert_eq!(Region::Unknown.to_string(), "unknown");
+        assert_eq!(Status::Archived.to_string(), "archived");
+
+        assert_eq!(
+            serde_json::to_string(&Region::UsEast1).unwrap(),
+            r#""us_east1""#
+        );
+        assert_eq!(Region::UsEast1.as_str(), "us_east1");
+    }
+
+    #[test]
+    fn test_accept_display() {
+        assert_eq!("in_progress".parse::<Status>().unwrap(), Status::InProgress);
+        assert_eq!("in PROGRESS".parse::<Status>().unwrap(), Status::InProgress);
+        assert_eq!("done".parse::<Status>().unwrap(), Status::Done);
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""In progress""#).unwrap(),
+            Status::Other("In progress".to_owned())
+        );
+
+        assert_eq!(
+            "US East (N. Virginia)".parse::<Region>().unwrap(),
+            Region::Unknown
+        );
+    }
+
+    #[test]
+    fn test_enum_str_set() {
+        use serde_enum_str::EnumStrSet;
+
+        let set = [Region::EuWest1, Region::UsEast1]
+            .into_iter()
+            .collect::<EnumStrSet<Region>>();
+        assert_eq!(set.to_string(), "us_east1,eu_west1");
+
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#""us_east1,eu_west1""#);
+        assert_eq!(
+            serde_json::from_str::<EnumStrSet<Region>>(&json).unwrap(),
+            set
+        );
+
+        let set = [Status::Other("blocked".to_owned()), Status::InProgress]
+            .into_iter()
+            .collect::<EnumStrSet<Status>>();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#""in_progress,blocked""#);
+        assert_eq!(
+            serde_json::from_str::<EnumStrSet<Status>>(&json).unwrap(),
+            set
+        );
+    }
+}
+
+#[cfg(test)]
+mod with_char {
+    use super::*;
+
+    use core::convert::TryFrom as _;
+
+    use serde::{
+        Deserialize as _,
+        de::{
+            IntoDeserializer as _,
+            value::{CharDeserializer, Error as ValueError},
+        },
+    };
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(char)]
+    enum Side {
+        #[serde(rename = '1')]
+        Buy,
+        #[serde(rename = "2", alias = "S")]
+        Sell,
+        #[serde(other)]
+        U,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(char)]
+    enum Status {
+        #[serde(rename = 'A')]
+        Accepted,
+        #[serde(rename = 'R')]
+        Rejected,
+        #[serde(other)]
+        Other(char),
+    }
+
+    #[test]
+    fn test_de() {
+        assert_eq!(serde_json::from_str::<Side>(r#""1""#).unwrap(), Side::Buy);
+        assert_eq!(serde_json::from_str::<Side>(r#""S""#).unwrap(), Side::Sell);
+        assert_eq!(serde_json::from_str::<Side>(r#""x""#).unwrap(), Side::U);
+        let deserializer: CharDeserializer<ValueError> = '2'.into_deserializer();
+        assert_eq!(Side::deserialize(deserializer).unwrap(), Side::Sell);
+
+        assert_eq!(
+            serde_json::from_str::<Status>(r#""X""#).unwrap(),
+            Status::Other('X')
+        );
+        assert!(serde_json::from_str::<Status>(r#""XY""#).is_err());
+    }
+
+    #[test]
+    fn test_ser() {
+        assert_eq!(serde_json::to_string(&Side::Buy).unwrap(), r#""1""#);
+        assert_eq!(serde_json::to_string(&Side::U).unwrap(), r#""U""#);
+        assert_eq!(
+            serde_json::to_string(&Status::Other('X')).unwrap(),
+            r#""X""#
+        );
+        assert_eq!(Status::Rejected.to_string(), "R");
+    }
+
+    #[test]
+    fn test_char() {
+        assert_eq!(Side::try_from('1').unwrap(), Side::Buy);
+        assert_eq!(Side::try_from('S').unwrap(), Side::Sell);
+        assert_eq!(Status::try_from('Z').unwrap(), Status::Other('Z'));
+        assert_eq!(char::from(Side::Sell), '2');
+        assert_eq!(char::from(Side::U), 'U');
+    }
+}
+
+#[cfg(test)]
+mod with_bytes {
+    use super::*;
+
+    use core::convert::TryFrom as _;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase")]
+    enum Method {
+        Get,
+        #[serde(ascii_case_insensitive)]
+        Post,
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Flag {
+        A,
+        #[serde(alias = "b")]
+        B,
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        assert_eq!(Method::from_bytes(b"get").unwrap(), Method::Get);
+        assert_eq!(Method::from_bytes(b"POST").unwrap(), Method::Post);
+        assert_eq!(
+            Method::try_from(&b"patch"[..]).unwrap(),
+            Method::Other("patch".to_owned())
+        );
+        assert_eq!(
+            Method::from_bytes(b"\xffx").unwrap_err().to_string(),
+            "invalid Method `\u{fffd}x`: invalid utf-8 sequence of 1 bytes from index 0"
+        );
+
+        assert_eq!(Flag::from_bytes(b"b").unwrap(), Flag::B);
+        assert_eq!(
+            Flag::try_from(&b"c"[..]).unwrap_err().to_string(),
+            "unknown Flag `c`; expected one of A, B"
+        );
+    }
+
+    #[test]
+    fn test_rmp_bin() {
+        // bin 8, as MessagePack writers that do not distinguish names from bytes send them.
+        let bin = |name: &[u8]| {
+            let mut bytes = vec![0xc4, name.len() as u8];
+            bytes.extend_from_slice(name);
+            bytes
+        };
+        assert_eq!(
+            rmp_serde::from_slice::<Method>(&bin(b"get")).unwrap(),
+            Method::Get
+        );
+        assert_eq!(
+            rmp_serde::from_slice::<Method>(&bin(b"patch")).unwrap(),
+            Method::Other("patch".to_owned())
+        );
+        assert_eq!(rmp_serde::from_slice::<Flag>(&bin(b"b")).unwrap(), Flag::B);
+        assert!(rmp_serde::from_slice::<Flag>(&bin(b"\xff")).is_err());
+    }
+}
+
+#[cfg(test)]
+mod with_match_tree {
+    use super::*;
+
+    use core::convert::TryFrom as _;
+
+    // More names than the threshold of the byte match tree.
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "UPPERCASE", accept_display)]
+    enum Currency {
+        Aud,
+        Brl,
+        Cad,
+        Chf,
+        Cny,
+        #[serde(alias = "RMB")]
+        Cnh,
+        Eur,
+        Gbp,
+        Hkd,
+        Inr,
+        #[serde(display = "yen")]
+        Jpy,
+        Krw,
+        Mxn,
+        Nok,
+        Nzd,
+        Sek,
+        #[serde(ascii_case_insensitive)]
+        Usd,
+        Zar,
+        #[serde(skip_deserializing)]
+        Xxx,
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[test]
+    fn test_de() {
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""AUD""#).unwrap(),
+            Currency::Aud
+        );
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""ZAR""#).unwrap(),
+            Currency::Zar
+        );
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""RMB""#).unwrap(),
+            Currency::Cnh
+        );
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""uSd""#).unwrap(),
+            Currency::Usd
+        );
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""XXX""#).unwrap(),
+            Currency::Other("XXX".to_owned())
+        );
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""aud""#).unwrap(),
+            Currency::Other("aud".to_owned())
+        );
+        assert_eq!(
+            serde_json::from_str::<Currency>(r#""AU""#).unwrap(),
+            Currency::Other("AU".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_from_str_and_bytes() {
+        assert_eq!("GBP".parse::<Currency>().unwrap(), Currency::Gbp);
+        assert_eq!("yen".parse::<Currency>().unwrap(), Currency::Jpy);
+        assert_eq!("usd".parse::<Currency>().unwrap(), Currency::Usd);
+        assert_eq!(Currency::try_from(&b"RMB"[..]).unwrap(), Currency::Cnh);
+        assert_eq!(Currency::from_bytes(b"Usd").unwrap(), Currency::Usd);
+        assert_eq!(Currency::from_bytes(b"yen").unwrap(), Currency::Jpy);
+        assert_eq!(
+            Currency::from_bytes(b"EURO").unwrap(),
+            Currency::Other("EURO".to_owned())
+        );
+    }
+}
+
+#[cfg(test)]
+mod with_other_attrs {
+    use super::*;
+
+    mod lowercase {
+        use serde::{Deserialize as _, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(value: &str, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(value)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+            String::deserialize(deserializer).map(|value| value.to_lowercase())
+        }
+    }
+
+    // Neither `Serialize`, `Deserialize` nor `Display`.
+    #[derive(PartialEq, Debug)]
+    struct Symbol(Box<str>);
+
+    fn serialize_symbol<S: serde::Serializer>(
+        symbol: &Symbol,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&symbol.0)
+    }
+
+    fn deserialize_symbol<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Symbol, D::Error> {
+        <String as serde::Deserialize>::deserialize(deserializer).map(|s| Symbol(s.into()))
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case")]
+    enum Level {
+        Low,
+        High,
+        #[serde(other, rename = "n/a")]
+        Unknown,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Lang {
+        Rust,
+        #[serde(other, with = "lowercase")]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    enum Tag {
+        Builtin,
+        #[serde(
+            other,
+            serialize_with = "serialize_symbol",
+            deserialize_with = "deserialize_symbol"
+        )]
+        Other(Symbol),
+    }
+
+    #[test]
+    fn test_rename() {
+        assert_eq!(serde_json::to_string(&Level::Unknown).unwrap(), r#""n/a""#);
+        assert_eq!(Level::Unknown.to_string(), "n/a");
+        assert_eq!(Level::Unknown.as_str(), "n/a");
+        assert_eq!(Level::VARIANTS, &["low", "high", "n/a"]);
+        assert_eq!(
+            serde_json::from_str::<Level>(r#""n/a""#).unwrap(),
+            Level::Unknown
+        );
+        assert_eq!("medium".parse::<Level>().unwrap(), Level::Unknown);
+    }
+
+    #[test]
+    fn test_with() {
+        assert_eq!(
+            serde_json::from_str::<Lang>(r#""Python""#).unwrap(),
+            Lang::Other("python".to_owned())
+        );
+        assert_eq!("Go".parse::<Lang>().unwrap(), Lang::Other("go".to_owned()));
+        assert_eq!("Rust".parse::<Lang>().unwrap(), Lang::Rust);
+        assert_eq!(
+            serde_json::to_string(&Lang::Other("python".to_owned())).unwrap(),
+            r#""python""#
+        );
+        assert_eq!(Lang::Other("go".to_owned()).to_string(), "go");
+    }
+
+    #[test]
+    fn test_serialize_with_deserialize_with() {
+        assert_eq!(
+            serde_json::from_str::<Tag>(r#""todo""#).unwrap(),
+            Tag::Other(Symbol("todo".into()))
+        );
+        assert_eq!(
+            "todo".parse::<Tag>().unwrap(),
+            Tag::Other(Symbol("todo".into()))
+        );
+        assert_eq!(
+            serde_json::to_string(&Tag::Other(Symbol("todo".into()))).unwrap(),
+            r#""todo""#
+        );
+        assert_eq!(Tag::Other(Symbol("todo".into())).to_string(), "todo");
+        assert_eq!(Tag::Builtin.to_string(), "Builtin");
+    }
+}
+
+#[cfg(test)]
+mod with_struct_str {
+    use core::{fmt, num::ParseIntError, str::FromStr};
+
+    use serde::{Deserialize, Serialize};
+    use serde_enum_str::{Deserialize_struct_str, Serialize_struct_str};
+
+    #[derive(Deserialize_struct_str, Serialize_struct_str, PartialEq, Debug)]
+    #[serde(expecting = "an account id")]
+    struct AccountId(u64);
+
+    impl fmt::Display for AccountId {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "acct_{}", self.0)
+        }
+    }
+
+    impl FromStr for AccountId {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.strip_prefix("acct_")
+                .ok_or_else(|| format!("{s:?} does not start with acct_"))?
+                .parse()
+                .map(Self)
+                .map_err(|err: ParseIntError| err.to_string())
+        }
+    }
+
+    #[derive(Deserialize_struct_str, Serialize_struct_str, PartialEq, Debug)]
+    struct Sku<T>(T);
+
+    impl<T: fmt::Display> fmt::Display for Sku<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    impl<T: FromStr> FromStr for Sku<T> {
+        type Err = T::Err;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse().map(Self)
+        }
+    }
+
+    #[derive(Deserialize, Serialize, PartialEq, Debug)]
+    struct Order {
+        account_id: AccountId,
+        sku: Sku<String>,
+    }
+
+    #[test]
+    fn test_ser_de() {
+        let order = Order {
+            account_id: AccountId(42),
+            sku: Sku("ABC-1".to_owned()),
+        };
+        let json = r#"{"account_id":"acct_42","sku":"ABC-1"}"#;
+        assert_eq!(serde_json::to_string(&order).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Order>(json).unwrap(), order);
+
+        assert_eq!(
+            rmp_serde::from_slice::<AccountId>(&rmp_serde::to_vec(&AccountId(7)).unwrap()).unwrap(),
+            AccountId(7)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            serde_json::from_str::<AccountId>(r#""42""#)
+                .unwrap_err()
+                .to_string(),
+            r#""42" does not start with acct_ at line 1 column 4"#
+        );
+        assert_eq!(
+            serde_json::from_str::<AccountId>("42")
+                .unwrap_err()
+                .to_string(),
+            "invalid type: integer `42`, expected an account id at line 1 column 2"
+        );
+        assert_eq!(
+            serde_json::from_str::<Sku<u8>>("1")
+                .unwrap_err()
+                .to_string(),
+            "invalid type: integer `1`, expected a string for Sku at line 1 column 1"
+        );
+    }
+}
+
+#[cfg(test)]
+mod with_str_interop {
+    use super::*;
+
+    use std::convert::Infallible;
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "kebab-case", str_interop)]
+    enum Encoding {
+        Gzip,
+        #[serde(display = "Brotli")]
+        Br,
+        #[serde(other, rename = "identity")]
+        Identity,
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase", str_interop)]
+    enum Method {
+        Get,
+        Post,
+        #[serde(other)]
+        Other(std::string::String),
+    }
+
+    mod text {
+        use std::{fmt, str::FromStr};
+
+        use serde::{Deserialize, Deserializer, de::Error as _};
+
+        /// Not the std `String`, every string doesn't parse.
+        #[derive(PartialEq, Debug)]
+        pub struct String(pub std::string::String);
+
+        impl FromStr for String {
+            type Err = fmt::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                if s.is_ascii() {
+                    Ok(Self(s.to_owned()))
+                } else {
+                    Err(fmt::Error)
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for String {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                std::string::String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(D::Error::custom)
+            }
+        }
+
+        impl AsRef<str> for String {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for std::string::String {
+            fn from(value: String) -> Self {
+                value.0
+            }
+        }
+
+        impl fmt::Display for String {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl serde::Serialize for String {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.0.serialize(serializer)
+            }
+        }
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase", str_interop)]
+    enum Word {
+        Yes,
+        #[serde(other)]
+        Other(text::String),
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(str_interop)]
+    enum Status {
+        Ok,
+        #[serde(pattern = "E{}")]
+        Error(u16),
+        #[serde(other)]
+        Code(u16),
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Encoding::Gzip, "gzip");
+        assert_eq!(Encoding::Br, *"br");
+        assert_ne!(Encoding::Br, "Brotli");
+        assert_eq!(Encoding::Identity, "identity");
+        assert_eq!(Method::Other("patch".to_owned()), "patch");
+        assert_ne!(Method::Other("patch".to_owned()), "pat");
+        assert_ne!(Method::Get, "GET");
+
+        assert_eq!(Status::Error(404), "E404");
+        assert_ne!(Status::Error(404), "E4040");
+        assert_eq!(Status::Code(7), "7");
+        assert_eq!(Status::Ok, "Ok");
+    }
+
+    #[test]
+    fn test_as_ref() {
+        assert_eq!(Encoding::Br.as_ref(), "br");
+        assert_eq!(Method::Other("patch".to_owned()).as_ref(), "patch");
+    }
+
+    #[test]
+    fn test_into_string() {
+        assert_eq!(String::from(Encoding::Identity), "identity");
+        assert_eq!(String::from(Method::Post), "post");
+        assert_eq!(String::from(Method::Other("patch".to_owned())), "patch");
+        assert_eq!(String::from(Status::Error(500)), "E500");
+        assert_eq!(String::from(Status::Code(7)), "7");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Method::from("post"), Method::Post);
+        assert_eq!(
+            Method::from("patch".to_owned()),
+            Method::Other("patch".to_owned())
+        );
+        fn try_from_str<T: for<'a> TryFrom<&'a str, Error = Infallible>>(value: &str) -> T {
+            match T::try_from(value) {
+                Ok(value) => value,
+            }
+        }
+        assert_eq!(try_from_str::<Method>("get"), Method::Get);
+
+        assert!(Status::try_from("x").is_err());
+        assert_eq!(Encoding::try_from("zstd").unwrap(), Encoding::Identity);
+    }
+
+    #[test]
+    fn test_try_from_str_with_other_string_type() {
+        assert_eq!(Word::try_from("yes").unwrap(), Word::Yes);
+        assert_eq!(
+            Word::try_from("maybe".to_owned()).unwrap(),
+            Word::Other(text::String("maybe".to_owned()))
+        );
+        assert!(Word::try_from("ja wohl ü").is_err());
+    }
+}
+
+#[cfg(test)]
+mod with_deprecated_alias {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    static HITS: Mutex<Vec<(&'static str, String)>> = Mutex::new(Vec::new());
+
+    fn on_deprecated(enum_name: &'static str, alias: &str) {
+        HITS.lock().unwrap().push((enum_name, alias.to_owned()));
+    }
+
+    #[derive(Deserialize_enum_str, Serialize_enum_str, PartialEq, Debug)]
+    #[serde(rename_all = "snake_case", on_deprecated = "on_deprecated")]
+    enum Plan {
+        #[serde(deprecated_alias = "basic", deprecated_alias = "starter")]
+        Free,
+        #[serde(
+            alias = "business",
+            deprecated_alias = "PREMIUM",
+            ascii_case_insensitive
+        )]
+        Pro,
+        #[serde(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize_enum_str, PartialEq, Debug)]
+    enum Large {
+        #[serde(deprecated_alias = "Zero")]
+        V0,
+        V1,
+        V2,
+        V3,
+        V4,
+        V5,
+        V6,
+        V7,
+        V8,
+        V9,
+        V10,
+        V11,
+        V12,
+        V13,
+        V14,
+        V15,
+        V16,
+    }
+
+    #[test]
+    fn test_deprecated_alias() {
+        assert_eq!("free".parse::<Plan>().unwrap(), Plan::Free);
+        assert_eq!("business".parse::<Plan>().unwrap(), Plan::Pro);
+        assert_eq!(Plan::deprecated_alias_hits(), 0);
+
+        assert_eq!("basic".parse::<Plan>().unwrap(), Plan::Free);
+        assert_eq!(
+            serde_json::from_str::<Plan>(r#""starter""#).unwrap(),
+            Plan::Free
+        );
+        assert_eq!(
+            serde_json::from_str::<Plan>(r#""premium""#).unwrap(),
+            Plan::Pro
+        );
+        assert_eq!(Plan::from_bytes(b"basic").unwrap(), Plan::Free);
+        assert_eq!(Plan::deprecated_alias_hits(), 4);
+        assert_eq!(
+            *HITS.lock().unwrap(),
+            vec![
+                ("Plan", "basic".to_owned()),
+                ("Plan", "starter".to_owned()),
+                ("Plan", "PREMIUM".to_owned()),
+                ("Plan", "basic".to_owned()),
+            ]
+        );
+
+        assert_eq!(Plan::Free.to_string(), "free");
+        assert_eq!(Plan::VARIANTS, &["free", "pro"]);
+        assert_eq!(
+            "bronze".parse::<Plan>().unwrap(),
+            Plan::Other("bronze".to_owned())
+        );
+        assert_eq!(Plan::deprecated_alias_hits(), 4);
+    }
+
+    #[test]
+    fn test_match_tree() {
+        assert_eq!("Zero".parse::<Large>().unwrap(), Large::V0);
+        assert_eq!("V16".parse::<Large>().unwrap(), Large::V16);
+        assert_eq!(Large::deprecated_alias_hits(), 1);
+    }
+}
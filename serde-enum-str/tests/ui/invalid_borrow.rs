@@ -0,0 +1,18 @@
+use serde_enum_str::Deserialize_enum_str;
+
+#[derive(Deserialize_enum_str)]
+enum Foo<'a> {
+    #[serde(borrow)]
+    A,
+    #[serde(other, borrow = "'b")]
+    Other(&'a str),
+}
+
+#[derive(Deserialize_enum_str)]
+enum Bar {
+    A,
+    #[serde(other, borrow)]
+    Other(String),
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use serde_enum_str::Serialize_enum_str;
+
+#[derive(Serialize_enum_str)]
+#[serde(char)]
+enum Foo {
+    #[serde(rename = 'A')]
+    A,
+    #[serde(rename = "BB")]
+    B,
+    #[serde(alias = "cc")]
+    C,
+    #[serde(pattern = "x{}")]
+    D(u8),
+}
+
+fn main() {}
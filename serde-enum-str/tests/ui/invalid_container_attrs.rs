@@ -0,0 +1,30 @@
+use serde_enum_str::Serialize_enum_str;
+
+#[derive(Serialize_enum_str)]
+#[serde(
+    display_rename_all = "Title Case",
+    compact_repr = "u128",
+    accept_discriminant = "float",
+    crate = "1 +"
+)]
+enum Foo {
+    A,
+}
+
+#[derive(Serialize_enum_str)]
+#[serde(rename_all = "SHOUTING", enum_str_crate = "::")]
+enum Bar {
+    A,
+}
+
+enum Remote {
+    A,
+}
+
+#[derive(Serialize_enum_str)]
+#[serde(remote = "Remote", str_interop)]
+enum RemoteDef {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use serde_enum_str::Deserialize_enum_str;
+
+#[derive(Deserialize_enum_str)]
+#[serde(on_deprecated = "not a path")]
+enum Foo {
+    #[serde(alias = "a", deprecated_alias = "a")]
+    A,
+    #[serde(deprecated_alias = "A")]
+    B,
+    #[serde(pattern = "x-{}", deprecated_alias = "y")]
+    C(String),
+    #[serde(other, deprecated_alias = "z")]
+    Other,
+}
+
+#[derive(Deserialize_enum_str)]
+#[serde(on_deprecated = "log_deprecated")]
+enum Bar {
+    #[serde(alias = "a")]
+    A,
+    B,
+}
+
+fn log_deprecated(_: &'static str, _: &str) {}
+
+fn main() {}
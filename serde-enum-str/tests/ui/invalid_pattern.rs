@@ -0,0 +1,15 @@
+use serde_enum_str::Serialize_enum_str;
+
+#[derive(Serialize_enum_str)]
+enum Foo {
+    #[serde(pattern = "v")]
+    A(u32),
+    #[serde(pattern = "{}-{}")]
+    B(String),
+    #[serde(pattern = "x-{}")]
+    C,
+    #[serde(pattern = "y-{}", rename = "y")]
+    D(String),
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use serde_enum_str::{Deserialize_struct_str, Serialize_struct_str};
+
+#[derive(Deserialize_struct_str, Serialize_struct_str)]
+enum Foo {
+    A,
+}
+
+#[derive(Deserialize_struct_str)]
+#[serde(rename_all = "snake_case")]
+struct Bar(u64);
+
+#[derive(Serialize_struct_str)]
+#[serde(crate = "not an expr")]
+struct Baz(u64);
+
+fn main() {}
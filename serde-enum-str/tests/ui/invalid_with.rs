@@ -0,0 +1,51 @@
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+enum Foo {
+    #[serde(with = "module")]
+    A,
+    #[serde(pattern = "x-{}", serialize_with = "f")]
+    B(String),
+    #[serde(other, deserialize_with = "f")]
+    C(String),
+    #[serde(other, with = "module")]
+    Other,
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+enum Bar {
+    A,
+    #[serde(other, rename = "b")]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+enum Baz {
+    A,
+    #[serde(other, with = "module", serialize_with = "f")]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+enum Qux {
+    A,
+    #[serde(other, deserialize_with = "not a path")]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+#[serde(compact_repr = "u8")]
+enum Quux {
+    A,
+    #[serde(other, with = "module")]
+    Other(String),
+}
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+enum Corge {
+    A,
+    #[serde(other, rename(serialize = "b", deserialize = "c"))]
+    Other,
+}
+
+fn main() {}
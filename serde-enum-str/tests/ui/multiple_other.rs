@@ -0,0 +1,14 @@
+use serde_enum_str::Deserialize_enum_str;
+
+#[derive(Deserialize_enum_str)]
+enum Foo {
+    A,
+    #[serde(other)]
+    B,
+    #[serde(other)]
+    C,
+    #[serde(other)]
+    D,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+#[derive(Deserialize_enum_str, Serialize_enum_str)]
+#[serde(rename_all = "snake_case")]
+enum Foo {
+    FooBar,
+    #[serde(rename = "foo_bar")]
+    Baz,
+    #[serde(alias = "foo_bar")]
+    Qux,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use serde_enum_str::Serialize_enum_str;
+
+#[derive(Serialize_enum_str)]
+struct Foo {
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use serde_enum_str::Serialize_enum_str;
+
+#[derive(Serialize_enum_str)]
+enum Foo {
+    A(u8),
+    B { b: u8 },
+    #[serde(other)]
+    Other(String, String),
+}
+
+fn main() {}
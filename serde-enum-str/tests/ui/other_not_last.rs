@@ -0,0 +1,11 @@
+use serde_enum_str::Serialize_enum_str;
+
+#[derive(Serialize_enum_str)]
+enum Foo {
+    A,
+    #[serde(other)]
+    Other(String),
+    B,
+}
+
+fn main() {}